@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::layout::Layout;
+
+pub const ENGLISH_LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+// the most keys in a row made up of letters the keyboard was missing
+const ROW_LENGTH: usize = 10;
+
+/// The letters a game is played with, how they sit on the keyboard and which
+/// other letters count as them, such as 'é' being guessed with 'e' in French.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Alphabet {
+    letters: Vec<char>,
+    keyboard: Vec<Vec<char>>,
+    // letters of a word mapped to the letter that guesses them, applied after
+    // lowercasing
+    #[serde(default)]
+    equivalents: BTreeMap<char, char>,
+}
+
+impl Alphabet {
+    // keys that aren't letters are left off the keyboard, and letters the
+    // keyboard is missing get rows of their own
+    pub fn new<I>(letters: &str, keyboard: &[&str], equivalents: I) -> Self
+    where
+        I: IntoIterator<Item = (char, char)>,
+    {
+        let mut alphabet = Self {
+            letters: vec![],
+            keyboard: vec![],
+            equivalents: BTreeMap::new(),
+        };
+        for c in letters.chars() {
+            let c = lowercase(c);
+            if !c.is_whitespace() && !alphabet.letters.contains(&c) {
+                alphabet.letters.push(c);
+            }
+        }
+        alphabet.equivalents = equivalents
+            .into_iter()
+            .map(|(from, to)| (lowercase(from), lowercase(to)))
+            .collect();
+
+        for row in keyboard {
+            let row: Vec<char> = row
+                .chars()
+                .map(lowercase)
+                .filter(|c| alphabet.letters.contains(c))
+                .collect();
+            if !row.is_empty() {
+                alphabet.keyboard.push(row);
+            }
+        }
+        let missing: Vec<char> = alphabet
+            .letters
+            .iter()
+            .copied()
+            .filter(|c| !alphabet.keyboard.iter().any(|row| row.contains(c)))
+            .collect();
+        alphabet
+            .keyboard
+            .extend(missing.chunks(ROW_LENGTH).map(<[char]>::to_vec));
+
+        alphabet
+    }
+
+    pub fn english() -> Self {
+        Self::english_in(Layout::default())
+    }
+
+    pub fn english_in(layout: Layout) -> Self {
+        Self::new(ENGLISH_LETTERS, layout.rows(), [])
+    }
+
+    // the same letters with the keyboard laid out like `layout`, only the
+    // english alphabet has layouts to pick from so any other is left as is
+    pub fn with_layout(self, layout: Layout) -> Self {
+        let english = Self::english_in(layout);
+        if self.letters == english.letters {
+            Self {
+                keyboard: english.keyboard,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    // the letter that guesses `c`
+    pub fn fold(&self, c: char) -> char {
+        let c = lowercase(c);
+        self.equivalents.get(&c).copied().unwrap_or(c)
+    }
+
+    // whether `c` has to be guessed, anything else such as spaces and
+    // punctuation is shown from the start
+    pub fn is_letter(&self, c: char) -> bool {
+        self.letters.contains(&self.fold(c))
+    }
+
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+
+    // the rows of the on screen keyboard, top to bottom
+    pub fn keyboard(&self) -> &[Vec<char>] {
+        &self.keyboard
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+// some uppercase letters lowercase to more than one character, the first is
+// the letter itself
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
@@ -0,0 +1,194 @@
+//! Plays every word in the difficulty lists against a solver and reports how
+//! often it wins, so words can be moved to the difficulty they belong in.
+//!
+//! usage: hangman-bench [--solver frequency|entropy] [--word-dir DIR] [--hardest N] [--json]
+
+use hangman::difficulty::Difficulty;
+use hangman::engine::{GameState, GameStatus};
+use hangman::solver::{self, EntropySolver, FrequencySolver, Solver};
+use hangman::words::{self, DirectoryWords, EmbeddedWords, WordSource, FULL_WORD_LIST};
+use serde::Serialize;
+use std::process;
+use strum::IntoEnumIterator;
+
+#[derive(Debug, Serialize)]
+struct WordResult {
+    word: String,
+    won: bool,
+    wrong: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    difficulty: String,
+    games: usize,
+    wins: usize,
+    win_rate: f64,
+    average_wrong: f64,
+    hardest: Vec<WordResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    solver: &'a str,
+    difficulties: &'a [Report],
+}
+
+struct Options {
+    solver: Box<dyn Solver>,
+    words: Box<dyn WordSource>,
+    hardest: usize,
+    json: bool,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: hangman-bench [--solver frequency|entropy] [--word-dir DIR] [--hardest N] [--json]"
+    );
+    process::exit(2);
+}
+
+fn parse_args() -> Options {
+    let mut options = Options {
+        solver: Box::new(FrequencySolver),
+        words: Box::new(EmbeddedWords::default()),
+        hardest: 10,
+        json: false,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--solver" => {
+                options.solver = match args.next().as_deref() {
+                    Some("frequency") => Box::new(FrequencySolver),
+                    Some("entropy") => Box::new(EntropySolver),
+                    _ => usage(),
+                }
+            }
+            "--word-dir" => match args.next() {
+                Some(dir) => options.words = Box::new(DirectoryWords::new(dir)),
+                None => usage(),
+            },
+            "--hardest" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) => options.hardest = n,
+                None => usage(),
+            },
+            "--json" => options.json = true,
+            _ => usage(),
+        }
+    }
+
+    options
+}
+
+fn bench(
+    difficulty: Difficulty,
+    words: &[String],
+    solver: &mut dyn Solver,
+    dictionary: &[String],
+    hardest: usize,
+) -> Report {
+    let mut results: Vec<WordResult> = words
+        .iter()
+        .map(|word| {
+            let mut game = GameState::new(word);
+            let status = solver::solve(&mut game, solver, dictionary);
+            WordResult {
+                word: word.clone(),
+                won: status == GameStatus::Won,
+                wrong: game.wrong_count(),
+            }
+        })
+        .collect();
+
+    let games = results.len();
+    let wins = results.iter().filter(|r| r.won).count();
+    let total_wrong: usize = results.iter().map(|r| r.wrong).sum();
+
+    // losses first, then the words that took the most wrong guesses
+    results.sort_by(|a, b| a.won.cmp(&b.won).then(b.wrong.cmp(&a.wrong)));
+    results.truncate(hardest);
+
+    Report {
+        difficulty: difficulty.as_str().to_string(),
+        games,
+        wins,
+        win_rate: if games == 0 {
+            0.0
+        } else {
+            wins as f64 / games as f64
+        },
+        average_wrong: if games == 0 {
+            0.0
+        } else {
+            total_wrong as f64 / games as f64
+        },
+        hardest: results,
+    }
+}
+
+fn print_table(solver: &str, reports: &[Report]) {
+    println!("solver: {}", solver);
+    println!();
+    println!(
+        "{:<10} {:>6} {:>6} {:>9} {:>10}",
+        "difficulty", "games", "wins", "win rate", "avg wrong"
+    );
+    for report in reports {
+        println!(
+            "{:<10} {:>6} {:>6} {:>8.1}% {:>10.2}",
+            report.difficulty,
+            report.games,
+            report.wins,
+            report.win_rate * 100.0,
+            report.average_wrong
+        );
+    }
+
+    for report in reports {
+        println!();
+        println!("hardest {} words:", report.difficulty);
+        for result in &report.hardest {
+            println!(
+                "  {:<16} {:>2} wrong{}",
+                result.word,
+                result.wrong,
+                if result.won { "" } else { " (lost)" }
+            );
+        }
+    }
+}
+
+fn main() {
+    let mut options = parse_args();
+    let dictionary = words::parse_list(FULL_WORD_LIST);
+
+    let mut reports = vec![];
+    for difficulty in Difficulty::iter() {
+        let words = match options.words.words(difficulty) {
+            Ok(words) => words.to_vec(),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        };
+        reports.push(bench(
+            difficulty,
+            &words,
+            options.solver.as_mut(),
+            &dictionary,
+            options.hardest,
+        ));
+    }
+
+    if options.json {
+        let output = Output {
+            solver: options.solver.name(),
+            difficulties: &reports,
+        };
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    } else {
+        print_table(options.solver.name(), &reports);
+    }
+}
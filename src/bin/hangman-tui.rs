@@ -0,0 +1,432 @@
+//! Plays hangman in the terminal, for when there is no window to draw in.
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Print, Stylize},
+    terminal::{self, ClearType},
+};
+use hangman::cli::{CliError, Options};
+use hangman::difficulty::Difficulty;
+use hangman::engine::{next_lives, GameMode, GameState, GameStatus, MAX_WRONG};
+use hangman::figure::{self, Stage};
+use hangman::layout::Layout;
+use hangman::pack::Pack;
+use hangman::rng::Rng;
+use hangman::words::{self, WordSource, WordSourceError};
+use std::{
+    io::{self, Write},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use strum::IntoEnumIterator;
+
+const FIGURE_ROWS: usize = 7;
+
+// phrases longer than this wrap between their words
+const WORD_WIDTH: usize = 40;
+
+// (row, column, text) for each piece of the figure
+fn stage_text(stage: Stage) -> &'static [(usize, usize, &'static str)] {
+    match stage {
+        Stage::BarOnBottom => &[(6, 0, "=====")],
+        Stage::DownOnLeft => &[
+            (1, 2, "|"),
+            (2, 2, "|"),
+            (3, 2, "|"),
+            (4, 2, "|"),
+            (5, 2, "|"),
+        ],
+        Stage::BarOnTop => &[(0, 2, "+-----+")],
+        Stage::DownOnRight => &[(1, 8, "|")],
+        Stage::Head => &[(2, 6, "(   )")],
+        Stage::Body => &[(3, 8, "|")],
+        Stage::LeftArm => &[(3, 7, "/")],
+        Stage::RightArm => &[(3, 9, "\\")],
+        Stage::LeftLeg => &[(4, 7, "/")],
+        Stage::RightLeg => &[(4, 9, "\\")],
+        Stage::LeftEye => &[(2, 7, "o")],
+        Stage::RightEye => &[(2, 9, "o")],
+        Stage::Mouth => &[(2, 8, "-")],
+        Stage::LeftHand => &[(3, 6, "-")],
+        Stage::RightHand => &[(3, 10, "-")],
+        Stage::LeftFoot => &[(4, 6, "_")],
+        Stage::RightFoot => &[(4, 10, "_")],
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum ScreenType {
+    Start,
+    Main,
+    End,
+}
+
+struct Tui {
+    screen_type: ScreenType,
+    mode: GameMode,
+    game: Option<GameState>,
+    max_wrong: usize,
+    layout: Layout,
+    // seed for the next game, from the command line
+    seed: Option<u64>,
+    words: Box<dyn WordSource>,
+    packs: Vec<Pack>,
+    // the pack new games pick from, `None` for the plain word lists
+    category: Option<usize>,
+    // the pack the word being played came from
+    game_category: Option<String>,
+    message: Option<String>,
+}
+
+// milliseconds since 1970, for the games and hints nobody asked to replay
+fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+// as much of the gallow and person as `num_wrong` of `max_wrong` calls for
+fn figure(num_wrong: usize, max_wrong: usize, lost: bool) -> Vec<String> {
+    let mut grid = vec![vec![' '; 12]; FIGURE_ROWS];
+
+    let visible = figure::visible(num_wrong, max_wrong);
+    for stage in &visible {
+        for (row, column, text) in stage_text(*stage) {
+            for (i, c) in text.chars().enumerate() {
+                grid[*row][column + i] = c;
+            }
+        }
+    }
+
+    // the eyes cross out once the game is lost, like the GUI turning them red
+    if lost && visible.contains(&Stage::LeftEye) && visible.contains(&Stage::RightEye) {
+        grid[2][7] = 'x';
+        grid[2][9] = 'x';
+    }
+
+    grid.into_iter()
+        .map(|line| line.into_iter().collect::<String>().trim_end().to_string())
+        .collect()
+}
+
+impl Tui {
+    fn new(words: Box<dyn WordSource>, packs: Vec<Pack>, options: &Options) -> Self {
+        let mut tui = Self {
+            screen_type: ScreenType::Start,
+            mode: options.mode.unwrap_or(GameMode::Normal),
+            game: None,
+            max_wrong: options.max_wrong.unwrap_or(MAX_WRONG),
+            layout: options.layout.unwrap_or_default(),
+            seed: options.seed,
+            words,
+            packs,
+            category: None,
+            game_category: None,
+            message: None,
+        };
+
+        if let Some(name) = &options.category {
+            match tui.packs.iter().position(|pack| pack.name == *name) {
+                Some(i) => tui.category = Some(i),
+                None => tui.message = Some(format!("unknown category {}", name)),
+            }
+        }
+
+        // a word or difficulty on the command line skips the start screen
+        if let Some(word) = &options.word {
+            tui.start(GameState::new(word));
+        } else if let Some(difficulty) = options.difficulty {
+            match tui.new_game(difficulty) {
+                Ok(game) => tui.start(game),
+                Err(err) => tui.message = Some(err.to_string()),
+            }
+        }
+
+        tui
+    }
+
+    fn start(&mut self, game: GameState) {
+        self.game = Some(game.with_max_wrong(self.max_wrong).with_layout(self.layout));
+        self.message = None;
+        self.screen_type = ScreenType::Main;
+    }
+
+    // every game gets its own seed so it can be played again with --seed
+    fn new_game(&mut self, difficulty: Difficulty) -> Result<GameState, WordSourceError> {
+        let seed = self.seed.take().unwrap_or_else(clock_seed);
+        // packs in other languages are played with their own alphabet
+        let alphabet = self
+            .category
+            .and_then(|i| self.packs[i].alphabet().cloned())
+            .unwrap_or_default();
+        let words: &mut dyn WordSource = match self.category {
+            Some(i) => &mut self.packs[i],
+            None => self.words.as_mut(),
+        };
+        let game = words::new_game(words, difficulty, self.mode, alphabet, seed)?;
+
+        self.game_category = self.category.map(|i| self.packs[i].name.clone());
+        Ok(game)
+    }
+
+    fn category_name(&self) -> &str {
+        self.category
+            .map_or("words", |i| self.packs[i].name.as_str())
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+
+        let mut lines: Vec<String> = vec![];
+        match self.screen_type {
+            ScreenType::Start => {
+                lines.push("Welcome to Hangman!".to_string());
+                lines.push("Select your difficulty below.".to_string());
+                lines.push(String::new());
+                lines.push("  1) easy   2) medium   3) hard".to_string());
+                lines.push(format!(
+                    "  c) {}   m) {} mode   l) {} lives   k) {} keyboard   q) quit",
+                    self.category_name(),
+                    self.mode.as_str(),
+                    self.max_wrong,
+                    self.layout.as_str()
+                ));
+            }
+            ScreenType::Main | ScreenType::End => {
+                let Some(game) = &self.game else {
+                    return Ok(());
+                };
+
+                if let Some(category) = &self.game_category {
+                    lines.push(format!("  category: {}", category));
+                    lines.push(String::new());
+                }
+                lines.extend(figure(
+                    game.wrong_count(),
+                    game.max_wrong(),
+                    game.status() == GameStatus::Lost,
+                ));
+                lines.push(String::new());
+
+                let word = if self.screen_type == ScreenType::End {
+                    game.word().to_string()
+                } else {
+                    game.masked_word()
+                };
+                let mut line = String::new();
+                for part in word.split(' ') {
+                    let part = part
+                        .chars()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    // counted in chars, letters outside ascii take more than
+                    // one byte but only one column
+                    let width = line.chars().count() + part.chars().count() + 3;
+                    if !line.is_empty() && width > WORD_WIDTH {
+                        lines.push(format!("  {}", line));
+                        line.clear();
+                    }
+                    if !line.is_empty() {
+                        line.push_str("   ");
+                    }
+                    line.push_str(&part);
+                }
+                lines.push(format!("  {}", line));
+                lines.push(String::new());
+                if let Some(clue) = game.clue() {
+                    lines.push(format!("  clue: {}", clue));
+                    lines.push(String::new());
+                }
+            }
+        }
+
+        for line in &lines {
+            queue!(out, Print(line), Print("\r\n"))?;
+        }
+
+        match self.screen_type {
+            ScreenType::Start => {}
+            ScreenType::Main => {
+                if let Some(game) = &self.game {
+                    // letters that have been used are dimmed like the gray
+                    // keys of the GUI keyboard
+                    for (i, row) in game.alphabet().keyboard().iter().enumerate() {
+                        queue!(out, Print(" ".repeat(2 + i)))?;
+                        for c in row {
+                            if game.letters().contains(c) {
+                                queue!(out, Print(c.bold()), Print(" "))?;
+                            } else {
+                                queue!(out, Print(c.dark_grey()), Print(" "))?;
+                            }
+                        }
+                        queue!(out, Print("\r\n"))?;
+                    }
+                    if game.can_hint() {
+                        queue!(out, Print("\r\n  ?) hint, costs a wrong guess"))?;
+                    }
+                    queue!(out, Print("\r\n  esc) quit\r\n"))?;
+                }
+            }
+            ScreenType::End => {
+                let won = self
+                    .game
+                    .as_ref()
+                    .is_some_and(|game| game.status() == GameStatus::Won);
+                queue!(
+                    out,
+                    Print(if won { "  You Won!" } else { "  You Lost!" }),
+                    Print("\r\n")
+                )?;
+                let hints = self.game.as_ref().map_or(0, GameState::hints);
+                if hints > 0 {
+                    queue!(out, Print(format!("  hints used: {}\r\n", hints)))?;
+                }
+                if let Some(seed) = self.game.as_ref().and_then(GameState::seed) {
+                    queue!(out, Print(format!("  seed: {}\r\n", seed)))?;
+                }
+                queue!(out, Print("\r\n  p) play again   q) quit\r\n"))?;
+            }
+        }
+
+        if let Some(message) = &self.message {
+            queue!(
+                out,
+                Print("\r\n"),
+                Print(message.as_str().red()),
+                Print("\r\n")
+            )?;
+        }
+
+        out.flush()
+    }
+
+    // returns false once the player wants to leave
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+
+        match self.screen_type {
+            ScreenType::Start => match key.code {
+                KeyCode::Char(c @ '1'..='3') => {
+                    let difficulty = match c {
+                        '1' => Difficulty::Easy,
+                        '2' => Difficulty::Medium,
+                        _ => Difficulty::Hard,
+                    };
+                    match self.new_game(difficulty) {
+                        Ok(game) => self.start(game),
+                        Err(err) => self.message = Some(err.to_string()),
+                    }
+                }
+                KeyCode::Char('m') => {
+                    self.mode = match self.mode {
+                        GameMode::Normal => GameMode::Evil,
+                        GameMode::Evil => GameMode::Normal,
+                    };
+                }
+                // cycles from the plain words through every pack
+                KeyCode::Char('c') => {
+                    self.category = match self.category {
+                        None if !self.packs.is_empty() => Some(0),
+                        Some(i) if i + 1 < self.packs.len() => Some(i + 1),
+                        _ => None,
+                    };
+                }
+                KeyCode::Char('l') => self.max_wrong = next_lives(self.max_wrong),
+                KeyCode::Char('k') => {
+                    let layouts: Vec<Layout> = Layout::iter().collect();
+                    let i = layouts.iter().position(|l| *l == self.layout).unwrap_or(0);
+                    self.layout = layouts[(i + 1) % layouts.len()];
+                }
+                KeyCode::Char('q') | KeyCode::Esc => return false,
+                _ => {}
+            },
+            ScreenType::Main => match key.code {
+                KeyCode::Esc => return false,
+                KeyCode::Char('?') => {
+                    if let Some(game) = self.game.as_mut() {
+                        game.hint(&Rng::new(game.hint_seed().unwrap_or_else(clock_seed)));
+                        // a hint can give away the last letter
+                        if game.is_over() {
+                            self.screen_type = ScreenType::End;
+                        }
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(game) = self.game.as_mut() {
+                        game.guess(c);
+                        if game.is_over() {
+                            self.screen_type = ScreenType::End;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            ScreenType::End => match key.code {
+                KeyCode::Char('p') => {
+                    self.game = None;
+                    self.screen_type = ScreenType::Start;
+                }
+                KeyCode::Char('q') | KeyCode::Esc => return false,
+                _ => {}
+            },
+        }
+
+        true
+    }
+
+    fn run(&mut self, out: &mut impl Write) -> io::Result<()> {
+        loop {
+            self.draw(out)?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !self.handle_key(key) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    // --theme and --fullscreen are accepted but only mean something to the GUI
+    let options = match Options::from_env() {
+        Ok(options) => options,
+        Err(CliError::Help) => {
+            println!("{}", CliError::Help);
+            return Ok(());
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    };
+    let words = match options.word_source() {
+        Ok(words) => words,
+        Err(err) => {
+            eprintln!("could not read word file: {}", err);
+            process::exit(1);
+        }
+    };
+    let (packs, errors) = options.packs();
+    for err in errors {
+        eprintln!("{}", err);
+    }
+    let mut tui = Tui::new(words, packs, &options);
+
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = tui.run(&mut out);
+
+    // put the terminal back even if the game failed
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    result
+}
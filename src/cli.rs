@@ -0,0 +1,240 @@
+use std::{error::Error, fmt, fs, path::PathBuf, str::FromStr};
+
+use crate::difficulty::Difficulty;
+use crate::engine::GameMode;
+use crate::figure::MAX_STAGES;
+use crate::layout::Layout;
+use crate::pack::{self, Pack, PackError};
+use crate::scoring::ScoredWords;
+use crate::words::{self, DirectoryWords, EmbeddedWords, MemoryWords, WordSource, FULL_WORD_LIST};
+
+pub const USAGE: &str = "\
+usage: hangman [options]
+
+options:
+  --difficulty <easy|medium|hard>  start a game straight away
+  --word <word>                    start a game with this secret word
+  --word-file <file>               pick words from this file, one per line
+  --scored-words                   sort words into difficulties by how hard
+                                   they are to guess instead of by length
+  --phrase-file <file>             pick phrases from this file, one per line
+  --pack-dir <dir>                 load word packs from this directory
+  --category <name>                pick words from this pack
+  --seed <number>                  seed for picking words
+  --max-wrong <1-17>               wrong guesses allowed before losing
+  --mode <normal|evil>             game mode
+  --layout <name>                  keyboard layout: qwerty, azerty, qwertz,
+                                   dvorak, colemak or alphabetical
+  --theme <name>                   color theme: dark, light, high-contrast,
+                                   solarized, colorblind or one of your own
+  --fullscreen                     start in fullscreen
+  -h, --help                       show this message";
+
+#[derive(Debug)]
+pub enum CliError {
+    Help,
+    MissingValue(String),
+    InvalidValue(String, String),
+    Unknown(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Help => write!(f, "{}", USAGE),
+            Self::MissingValue(option) => write!(f, "{} needs a value\n\n{}", option, USAGE),
+            Self::InvalidValue(option, value) => {
+                write!(f, "invalid value for {}: {}\n\n{}", option, value, USAGE)
+            }
+            Self::Unknown(arg) => write!(f, "unknown argument: {}\n\n{}", arg, USAGE),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+/// How the game was asked to start from the command line.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Options {
+    pub difficulty: Option<Difficulty>,
+    pub word: Option<String>,
+    pub word_file: Option<PathBuf>,
+    pub scored_words: bool,
+    pub phrase_file: Option<PathBuf>,
+    pub pack_dir: Option<PathBuf>,
+    pub category: Option<String>,
+    pub seed: Option<u64>,
+    pub max_wrong: Option<usize>,
+    pub mode: Option<GameMode>,
+    pub layout: Option<Layout>,
+    pub theme: Option<String>,
+    pub fullscreen: bool,
+}
+
+fn parse_value<T: FromStr>(option: &str, value: Option<String>) -> Result<T, CliError> {
+    let value = value.ok_or_else(|| CliError::MissingValue(option.to_string()))?;
+    value
+        .parse()
+        .map_err(|_| CliError::InvalidValue(option.to_string(), value))
+}
+
+impl Options {
+    pub fn from_env() -> Result<Self, CliError> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut options = Self::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--difficulty" => options.difficulty = Some(parse_value(&arg, args.next())?),
+                "--word" => {
+                    let word: String = parse_value(&arg, args.next())?;
+                    let word = word.trim().to_lowercase();
+                    if words::check_secret_word(&word, None).is_err() {
+                        return Err(CliError::InvalidValue(arg, word));
+                    }
+                    options.word = Some(word);
+                }
+                "--word-file" => options.word_file = Some(parse_value(&arg, args.next())?),
+                "--scored-words" => options.scored_words = true,
+                "--phrase-file" => options.phrase_file = Some(parse_value(&arg, args.next())?),
+                "--pack-dir" => options.pack_dir = Some(parse_value(&arg, args.next())?),
+                "--category" => options.category = Some(parse_value(&arg, args.next())?),
+                "--seed" => options.seed = Some(parse_value(&arg, args.next())?),
+                "--max-wrong" => {
+                    let max_wrong: usize = parse_value(&arg, args.next())?;
+                    // past MAX_STAGES some wrong guesses would not draw anything
+                    if !(1..=MAX_STAGES).contains(&max_wrong) {
+                        return Err(CliError::InvalidValue(arg, max_wrong.to_string()));
+                    }
+                    options.max_wrong = Some(max_wrong);
+                }
+                "--mode" => options.mode = Some(parse_value(&arg, args.next())?),
+                "--layout" => options.layout = Some(parse_value(&arg, args.next())?),
+                "--theme" => options.theme = Some(parse_value(&arg, args.next())?),
+                "--fullscreen" => options.fullscreen = true,
+                "-h" | "--help" => return Err(CliError::Help),
+                _ => return Err(CliError::Unknown(arg)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    // --word-file wins over HANGMAN_WORD_DIR, which wins over --scored-words
+    // (or HANGMAN_SCORED_WORDS), and the built in lists are used otherwise.
+    // Scoring plays a game for every word, so call this before the game loop
+    pub fn word_source(&self) -> Result<Box<dyn WordSource>, std::io::Error> {
+        if let Some(path) = &self.word_file {
+            let text = fs::read_to_string(path)?;
+            Ok(Box::new(MemoryWords::new(words::parse_list(&text))))
+        } else if let Some(dir) = std::env::var_os("HANGMAN_WORD_DIR") {
+            Ok(Box::new(DirectoryWords::new(dir)))
+        } else if self.scored_words || std::env::var_os("HANGMAN_SCORED_WORDS").is_some() {
+            Ok(Box::new(ScoredWords::new(words::parse_list(
+                FULL_WORD_LIST,
+            ))))
+        } else {
+            Ok(Box::new(EmbeddedWords::default()))
+        }
+    }
+
+    // the bundled packs followed by any in --pack-dir, or the default pack
+    // directory. A pack that can't be loaded is left out and its error
+    // returned alongside the rest
+    pub fn packs(&self) -> (Vec<Pack>, Vec<PackError>) {
+        let mut packs = Pack::bundled();
+        let mut errors = vec![];
+
+        if let Some(path) = &self.phrase_file {
+            match fs::read_to_string(path) {
+                // the phrases are always the first bundled pack
+                Ok(text) => packs[0] = Pack::phrases(&text),
+                Err(err) => errors.push(PackError::Io(path.clone(), err)),
+            }
+        }
+
+        let dir = self.pack_dir.clone().or_else(pack::default_dir);
+        match dir.as_deref().map(pack::load_dir) {
+            Some(Ok(loaded)) => {
+                for pack in loaded {
+                    match pack {
+                        Ok(pack) => packs.push(pack),
+                        Err(err) => errors.push(err),
+                    }
+                }
+            }
+            Some(Err(err)) => errors.push(err),
+            None => {}
+        }
+
+        (packs, errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, CliError> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn nothing_is_the_default() {
+        assert_eq!(parse(&[]).unwrap(), Options::default());
+    }
+
+    #[test]
+    fn options_are_read() {
+        let options = parse(&["--difficulty", "hard", "--seed", "42", "--mode", "evil"]).unwrap();
+
+        assert_eq!(options.difficulty, Some(Difficulty::Hard));
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.mode, Some(GameMode::Evil));
+    }
+
+    #[test]
+    fn the_word_is_checked() {
+        assert_eq!(
+            parse(&["--word", " Rust "]).unwrap().word.as_deref(),
+            Some("rust")
+        );
+        assert!(matches!(
+            parse(&["--word", "r2d2"]),
+            Err(CliError::InvalidValue(option, _)) if option == "--word"
+        ));
+    }
+
+    #[test]
+    fn max_wrong_has_to_fit_the_figure() {
+        assert_eq!(parse(&["--max-wrong", "1"]).unwrap().max_wrong, Some(1));
+        assert_eq!(parse(&["--max-wrong", "17"]).unwrap().max_wrong, Some(17));
+        for value in ["0", "18", "lots"] {
+            assert!(matches!(
+                parse(&["--max-wrong", value]),
+                Err(CliError::InvalidValue(_, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn bad_arguments_are_errors() {
+        assert!(matches!(
+            parse(&["--seed"]),
+            Err(CliError::MissingValue(option)) if option == "--seed"
+        ));
+        assert!(matches!(
+            parse(&["--colour"]),
+            Err(CliError::Unknown(arg)) if arg == "--colour"
+        ));
+        assert!(matches!(parse(&["-h"]), Err(CliError::Help)));
+        assert!(matches!(
+            parse(&["--seed", "1", "--help"]),
+            Err(CliError::Help)
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+// a file or directory in the game's own config dir, `None` when the platform
+// has no config dir
+pub fn path(name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("hangman").join(name))
+}
+
+// the text of the file, `None` when it hasn't been written yet
+pub fn read(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// writes the file, making the directories it goes in first
+pub fn write(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, text)
+}
+
+// loads every file in the directory with one of the extensions, sorted by
+// file name. A missing directory just has nothing in it, and a file that
+// can't be loaded is returned as its error alongside the rest
+pub fn load_dir<T, E>(
+    dir: &Path,
+    extensions: &[&str],
+    load: impl Fn(&Path) -> Result<T, E>,
+) -> io::Result<Vec<Result<T, E>>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if extension.is_some_and(|ext| extensions.contains(&ext)) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    Ok(paths.iter().map(|path| load(path)).collect())
+}
@@ -0,0 +1,121 @@
+use quad_rand::ChooseRandom;
+
+use crate::engine::{GameState, GameStatus};
+use crate::rng::Rng;
+use crate::words::{self, MEDIUM_WORDS};
+
+// "hangman" in ascii, keeps the daily seeds apart from the ones picked from the clock
+const DAILY_SALT: u64 = 0x0068_616e_676d_616e;
+
+// days since 1970-01-01 in UTC, so everyone is on the same day at once.
+// `now` is seconds since then, passed in because the clock is read
+// differently on each platform (std's clock panics on wasm)
+pub fn today(now: f64) -> u64 {
+    (now.max(0.0) / 86_400.0) as u64
+}
+
+// the day as YYYY-MM-DD, see http://howardhinnant.github.io/date_algorithms.html
+pub fn date(day: u64) -> String {
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+pub fn seed(day: u64) -> u64 {
+    day ^ DAILY_SALT
+}
+
+// the same word for everyone on the same day, from the built in medium list
+pub fn word(day: u64) -> String {
+    let list: Vec<String> = words::parse_list(MEDIUM_WORDS)
+        .into_iter()
+        .filter(|word| word.chars().all(|c| c.is_ascii_lowercase()))
+        .collect();
+
+    list.choose_with_state(&Rng::new(seed(day)))
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub fn game(day: u64) -> GameState {
+    GameState::new(&word(day)).with_seed(seed(day))
+}
+
+// a summary that can be shared without giving the word away
+pub fn share_text(day: u64, game: &GameState) -> String {
+    let result = match game.status() {
+        GameStatus::Won => game.wrong_count().to_string(),
+        _ => "X".to_string(),
+    };
+    let grid: String = game
+        .history()
+        .iter()
+        .map(|c| if game.contains(*c) { '🟩' } else { '🟥' })
+        .collect();
+
+    // hints count towards the result, a bulb for each one says how many
+    let hints = "💡".repeat(game.hints());
+
+    format!(
+        "Hangman Daily {} {}/{}\n{}{}",
+        date(day),
+        result,
+        game.max_wrong(),
+        grid,
+        hints
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_become_dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(59), "1970-03-01");
+        assert_eq!(date(11_016), "2000-02-29");
+        assert_eq!(date(20_744), "2026-10-18");
+    }
+
+    #[test]
+    fn today_counts_whole_days() {
+        assert_eq!(today(0.0), 0);
+        assert_eq!(today(86_399.9), 0);
+        assert_eq!(today(86_400.0), 1);
+    }
+
+    #[test]
+    fn everyone_gets_the_same_word() {
+        assert_eq!(word(20_744), word(20_744));
+        assert_eq!(game(20_744).seed(), Some(seed(20_744)));
+    }
+
+    #[test]
+    fn share_text_hides_the_word() {
+        let mut won = GameState::new("ab");
+        won.guess('z');
+        won.guess('a');
+        won.hint(&Rng::new(0));
+        assert_eq!(won.status(), GameStatus::Won);
+        assert_eq!(share_text(0, &won), "Hangman Daily 1970-01-01 2/9\n🟥🟩💡");
+
+        let mut lost = GameState::new("ab").with_max_wrong(2);
+        lost.guess('a');
+        lost.guess('x');
+        lost.guess('y');
+        assert_eq!(lost.status(), GameStatus::Lost);
+        assert_eq!(
+            share_text(59, &lost),
+            "Hangman Daily 1970-03-01 X/2\n🟩🟥🟥"
+        );
+    }
+}
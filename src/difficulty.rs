@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{EnumCount as EnumCountMacro, EnumIter, EnumString};
+
+#[derive(
+    Debug,
+    EnumCountMacro,
+    EnumIter,
+    EnumString,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Difficulty {
+    // by length, or by thirds of the scores with --scored-words
+    Easy,   // up to 5 letters, or the easiest third
+    Medium, // 6..=9 letters, or the middle third
+    Hard,   // 10+ letters, or the hardest third
+}
+
+impl Difficulty {
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "easy" => Self::Easy,
+            "medium" => Self::Medium,
+            "hard" => Self::Hard,
+            _ => unreachable!(),
+        }
+    }
+
+    // the bucket a word of this length belongs in
+    pub fn from_length(len: usize) -> Self {
+        match len {
+            0..=5 => Self::Easy,
+            6..=9 => Self::Medium,
+            _ => Self::Hard,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use quad_rand::{ChooseRandom, RandGenerator};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashSet},
+};
+use strum_macros::{EnumIter, EnumString};
+
+use crate::alphabet::Alphabet;
+use crate::layout::Layout;
+
+pub const MAX_WRONG: usize = 9;
+// the numbers of lives the frontends offer to pick between
+pub const LIVES: [usize; 3] = [6, MAX_WRONG, 12];
+
+// the next bigger choice of lives after `lives`, back to the smallest after
+// the biggest
+pub fn next_lives(lives: usize) -> usize {
+    LIVES
+        .iter()
+        .copied()
+        .find(|choice| *choice > lives)
+        .unwrap_or(LIVES[0])
+}
+
+// the word with every letter replaced by '_', which candidates for an evil
+// game have to share
+fn shape(word: &str, alphabet: &Alphabet) -> String {
+    word.chars()
+        .map(|c| if alphabet.is_letter(c) { '_' } else { c })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GuessOutcome {
+    Correct,
+    Wrong,
+    AlreadyGuessed,
+    Invalid,
+    GameOver,
+}
+
+#[derive(Debug, EnumIter, EnumString, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum GameMode {
+    Normal,
+    // the word is only committed to once the guesses force it
+    Evil,
+}
+
+impl GameMode {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Self::Normal => "normal",
+            Self::Evil => "evil",
+        }
+    }
+}
+
+/// What a hint gave away, each one costs a wrong guess.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Hint {
+    Clue(String),
+    Letter(char),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// The rules of a single game of hangman, independent of any frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameState {
+    word: String,
+    guess: Vec<char>,
+    letters: HashSet<char>,
+    letters_wrong: Vec<char>,
+    max_wrong: usize,
+    mode: GameMode,
+    // words that still fit every guess so far, only used in evil mode
+    candidates: Vec<String>,
+    // the seed the word was picked with, if it was picked at random
+    #[serde(default)]
+    seed: Option<u64>,
+    // every letter guessed, in the order it was guessed
+    #[serde(default)]
+    history: Vec<char>,
+    // a definition or clue for the word, given away by the first hint
+    #[serde(default)]
+    clue: Option<String>,
+    #[serde(default)]
+    clue_shown: bool,
+    // how many hints were taken, each counts as a wrong guess
+    #[serde(default)]
+    hints: usize,
+    // games saved before there were other alphabets were all english
+    #[serde(default)]
+    alphabet: Alphabet,
+}
+
+impl GameState {
+    pub fn new(word: &str) -> Self {
+        Self::new_in(word, Alphabet::default())
+    }
+
+    pub fn new_in(word: &str, alphabet: Alphabet) -> Self {
+        Self {
+            word: word.to_lowercase(),
+            guess: vec![],
+            letters: alphabet.letters().iter().copied().collect(),
+            letters_wrong: vec![],
+            max_wrong: MAX_WRONG,
+            mode: GameMode::Normal,
+            candidates: vec![],
+            seed: None,
+            history: vec![],
+            clue: None,
+            clue_shown: false,
+            hints: 0,
+            alphabet,
+        }
+    }
+
+    // starts an evil game, `word` only decides the length (and where any
+    // spaces or punctuation go) and every candidate of that shape stays in
+    // play until the guesses narrow them down
+    pub fn evil(word: &str, candidates: &[String]) -> Self {
+        Self::evil_in(word, candidates, Alphabet::default())
+    }
+
+    pub fn evil_in(word: &str, candidates: &[String], alphabet: Alphabet) -> Self {
+        let pattern = shape(&word.to_lowercase(), &alphabet);
+
+        Self {
+            mode: GameMode::Evil,
+            candidates: candidates
+                .iter()
+                .map(|candidate| candidate.to_lowercase())
+                .filter(|candidate| shape(candidate, &alphabet) == pattern)
+                .collect(),
+            ..Self::new_in(word, alphabet)
+        }
+    }
+
+    pub fn with_max_wrong(mut self, max_wrong: usize) -> Self {
+        self.max_wrong = max_wrong;
+        self
+    }
+
+    // games in other alphabets keep their own keyboard
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.alphabet = self.alphabet.with_layout(layout);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn with_clue(mut self, clue: Option<String>) -> Self {
+        self.clue = clue;
+        self
+    }
+
+    // whether a hint can be taken without its penalty losing the game
+    pub fn can_hint(&self) -> bool {
+        !self.is_over()
+            && self.wrong_count() + 1 < self.max_wrong
+            && ((self.clue.is_some() && !self.clue_shown) || self.masked_word().contains('_'))
+    }
+
+    // gives away the clue if there is one that hasn't been shown yet, otherwise
+    // reveals a random letter that hasn't been found, at the cost of a wrong
+    // guess either way
+    pub fn hint(&mut self, rng: &RandGenerator) -> Option<Hint> {
+        if !self.can_hint() {
+            return None;
+        }
+
+        let hint = match &self.clue {
+            Some(clue) if !self.clue_shown => {
+                self.clue_shown = true;
+                Hint::Clue(clue.clone())
+            }
+            _ => {
+                let mut hidden: Vec<char> = self
+                    .word
+                    .chars()
+                    .filter(|c| self.alphabet.is_letter(*c))
+                    .map(|c| self.alphabet.fold(c))
+                    .filter(|c| !self.guess.contains(c))
+                    .collect();
+                hidden.sort_unstable();
+                hidden.dedup();
+
+                let letter = *hidden.choose_with_state(rng)?;
+                // in evil mode the letter pins down where it goes, so only
+                // candidates with it in the same places are kept
+                if self.mode == GameMode::Evil {
+                    let positions = self.positions(&self.word, letter);
+                    let candidates = std::mem::take(&mut self.candidates);
+                    self.candidates = candidates
+                        .into_iter()
+                        .filter(|candidate| self.positions(candidate, letter) == positions)
+                        .collect();
+                }
+                self.letters.remove(&letter);
+                self.guess.push(letter);
+                Hint::Letter(letter)
+            }
+        };
+        self.hints += 1;
+
+        Some(hint)
+    }
+
+    pub fn guess(&mut self, letter: char) -> GuessOutcome {
+        if self.is_over() {
+            return GuessOutcome::GameOver;
+        }
+
+        let letter = self.alphabet.fold(letter);
+        if !self.alphabet.letters().contains(&letter) {
+            return GuessOutcome::Invalid;
+        }
+        if !self.letters.remove(&letter) {
+            return GuessOutcome::AlreadyGuessed;
+        }
+        if self.mode == GameMode::Evil {
+            self.narrow_candidates(letter);
+        }
+        self.history.push(letter);
+
+        if self.contains(letter) {
+            self.guess.push(letter);
+            GuessOutcome::Correct
+        } else {
+            self.letters_wrong.push(letter);
+            GuessOutcome::Wrong
+        }
+    }
+
+    // where the letter, or any letter it stands for, is in the word
+    fn positions(&self, word: &str, letter: char) -> Vec<usize> {
+        word.chars()
+            .enumerate()
+            .filter(|(_, c)| self.alphabet.fold(*c) == letter)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // split the candidates by where the letter would appear and keep the
+    // biggest family, preferring the one that reveals the least
+    fn narrow_candidates(&mut self, letter: char) {
+        let mut families: BTreeMap<Vec<usize>, Vec<String>> = BTreeMap::new();
+        for word in std::mem::take(&mut self.candidates) {
+            let positions = self.positions(&word, letter);
+            families.entry(positions).or_default().push(word);
+        }
+
+        let family = families
+            .into_iter()
+            .max_by_key(|(positions, words)| (words.len(), Reverse(positions.len())))
+            .map(|(_, words)| words)
+            .unwrap_or_default();
+
+        if let Some(word) = family.first() {
+            self.word = word.clone();
+        }
+        self.candidates = family;
+    }
+
+    pub fn status(&self) -> GameStatus {
+        if self.wrong_count() >= self.max_wrong {
+            GameStatus::Lost
+        } else if self
+            .word
+            .chars()
+            .filter(|c| self.alphabet.is_letter(*c))
+            .all(|c| self.guess.contains(&self.alphabet.fold(c)))
+        {
+            GameStatus::Won
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status() != GameStatus::InProgress
+    }
+
+    // every letter that has not been guessed yet is replaced by '_'
+    pub fn masked_word(&self) -> String {
+        self.word
+            .chars()
+            .map(|c| {
+                if !self.alphabet.is_letter(c) || self.guess.contains(&self.alphabet.fold(c)) {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    // whether the word has the letter, or a letter it stands for
+    pub fn contains(&self, letter: char) -> bool {
+        self.word.chars().any(|c| self.alphabet.fold(c) == letter)
+    }
+
+    // in evil mode this is whichever word the game has committed to so far
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    // letters that are still available to guess
+    pub fn letters(&self) -> &HashSet<char> {
+        &self.letters
+    }
+
+    pub fn letters_wrong(&self) -> &[char] {
+        &self.letters_wrong
+    }
+
+    // wrong letters and hints together, which is what the drawing shows
+    pub fn wrong_count(&self) -> usize {
+        self.letters_wrong.len() + self.hints
+    }
+
+    pub fn max_wrong(&self) -> usize {
+        self.max_wrong
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn history(&self) -> &[char] {
+        &self.history
+    }
+
+    // the clue once a hint has shown it
+    pub fn clue(&self) -> Option<&str> {
+        self.clue.as_deref().filter(|_| self.clue_shown)
+    }
+
+    pub fn has_clue(&self) -> bool {
+        self.clue.is_some()
+    }
+
+    pub fn hints(&self) -> usize {
+        self.hints
+    }
+
+    // what to seed the next hint's rng with. It comes from the game's seed
+    // and the hints taken so far, so a game replayed with --seed gets the
+    // same hints. Games with no seed, such as a word typed in by the other
+    // player, have `None` and should use the clock so hints can't be guessed
+    pub fn hint_seed(&self) -> Option<u64> {
+        self.seed.map(|seed| seed.wrapping_add(self.hints as u64))
+    }
+
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::ENGLISH_LETTERS;
+    use crate::rng::Rng;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn guessing_every_letter_wins() {
+        let mut game = GameState::new("Level");
+        assert_eq!(game.masked_word(), "_____");
+
+        assert_eq!(game.guess('l'), GuessOutcome::Correct);
+        assert_eq!(game.guess('E'), GuessOutcome::Correct);
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert_eq!(game.guess('v'), GuessOutcome::Correct);
+
+        assert_eq!(game.status(), GameStatus::Won);
+        assert_eq!(game.masked_word(), "level");
+        assert_eq!(game.wrong_count(), 0);
+    }
+
+    #[test]
+    fn running_out_of_guesses_loses() {
+        let mut game = GameState::new("cat").with_max_wrong(2);
+
+        assert_eq!(game.guess('x'), GuessOutcome::Wrong);
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert_eq!(game.guess('y'), GuessOutcome::Wrong);
+
+        assert_eq!(game.status(), GameStatus::Lost);
+        assert_eq!(game.letters_wrong(), ['x', 'y']);
+        assert_eq!(game.guess('c'), GuessOutcome::GameOver);
+    }
+
+    #[test]
+    fn repeated_and_invalid_guesses_cost_nothing() {
+        let mut game = GameState::new("cat");
+
+        assert_eq!(game.guess('x'), GuessOutcome::Wrong);
+        assert_eq!(game.guess('x'), GuessOutcome::AlreadyGuessed);
+        assert_eq!(game.guess('c'), GuessOutcome::Correct);
+        assert_eq!(game.guess('C'), GuessOutcome::AlreadyGuessed);
+        assert_eq!(game.guess('1'), GuessOutcome::Invalid);
+
+        assert_eq!(game.wrong_count(), 1);
+        assert_eq!(game.history(), ['x', 'c']);
+        assert!(!game.letters().contains(&'x'));
+    }
+
+    #[test]
+    fn phrases_show_spaces_and_punctuation() {
+        let mut game = GameState::new("it's ok");
+        assert_eq!(game.masked_word(), "__'_ __");
+
+        for c in "itsok".chars() {
+            game.guess(c);
+        }
+
+        assert_eq!(game.status(), GameStatus::Won);
+        assert_eq!(game.masked_word(), "it's ok");
+    }
+
+    #[test]
+    fn accented_letters_are_guessed_with_their_letter() {
+        let alphabet = Alphabet::new(ENGLISH_LETTERS, &[], [('é', 'e'), ('è', 'e')]);
+        let mut game = GameState::new_in("élève", alphabet);
+        assert_eq!(game.masked_word(), "_____");
+
+        assert_eq!(game.guess('e'), GuessOutcome::Correct);
+        assert_eq!(game.masked_word(), "é_è_e");
+        assert_eq!(game.guess('é'), GuessOutcome::AlreadyGuessed);
+        assert!(game.contains('e'));
+
+        game.guess('l');
+        game.guess('v');
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn hints_cost_a_wrong_guess() {
+        let mut game = GameState::new("abc").with_max_wrong(3);
+        let rng = Rng::new(game.hint_seed().unwrap_or_default());
+
+        assert!(game.can_hint());
+        assert!(matches!(game.hint(&rng), Some(Hint::Letter(_))));
+        assert_eq!(game.hints(), 1);
+        assert_eq!(game.wrong_count(), 1);
+        assert_eq!(game.masked_word().matches('_').count(), 2);
+
+        // a hint that would lose the game isn't given
+        game.guess('x');
+        assert!(!game.can_hint());
+        assert_eq!(game.hint(&rng), None);
+        assert_eq!(game.wrong_count(), 2);
+        assert_eq!(game.status(), GameStatus::InProgress);
+    }
+
+    #[test]
+    fn the_clue_is_the_first_hint() {
+        let mut game = GameState::new("abc").with_clue(Some("the start".to_string()));
+        let rng = Rng::new(0);
+
+        assert_eq!(game.clue(), None);
+        assert_eq!(game.hint(&rng), Some(Hint::Clue("the start".to_string())));
+        assert_eq!(game.clue(), Some("the start"));
+        assert!(matches!(game.hint(&rng), Some(Hint::Letter(_))));
+        assert_eq!(game.wrong_count(), 2);
+    }
+
+    #[test]
+    fn evil_games_keep_the_biggest_family() {
+        let candidates = words(&["cat", "cot", "cut", "dog", "bat", "goat"]);
+        let mut game = GameState::evil("cat", &candidates);
+
+        // "goat" is the wrong length so it was never in play, and more of
+        // the rest start with 'c' than don't
+        assert_eq!(game.guess('c'), GuessOutcome::Correct);
+        assert_eq!(game.masked_word(), "c__");
+
+        // only "cat" has an 'a', so the game dodges it
+        assert_eq!(game.guess('a'), GuessOutcome::Wrong);
+        assert!(["cot", "cut"].contains(&game.word()));
+    }
+
+    #[test]
+    fn evil_games_reveal_as_little_as_they_can() {
+        let candidates = words(&["ab", "ba", "aa", "bb"]);
+        let mut game = GameState::evil("aa", &candidates);
+
+        // every family has one word, so the one without the letter is kept
+        assert_eq!(game.guess('a'), GuessOutcome::Wrong);
+        assert_eq!(game.word(), "bb");
+    }
+}
@@ -0,0 +1,132 @@
+use strum_macros::EnumIter;
+
+/// One piece of the drawing that wrong guesses build up, gallow included.
+#[derive(Debug, EnumIter, Copy, Clone, Eq, PartialEq)]
+pub enum Stage {
+    BarOnBottom,
+    DownOnLeft,
+    BarOnTop,
+    DownOnRight,
+    Head,
+    Body,
+    LeftArm,
+    RightArm,
+    LeftLeg,
+    RightLeg,
+    LeftEye,
+    RightEye,
+    Mouth,
+    LeftHand,
+    RightHand,
+    LeftFoot,
+    RightFoot,
+}
+
+const GALLOW: [Stage; 4] = [
+    Stage::BarOnBottom,
+    Stage::DownOnLeft,
+    Stage::BarOnTop,
+    Stage::DownOnRight,
+];
+
+const PERSON: [Stage; 9] = [
+    Stage::Head,
+    Stage::Body,
+    Stage::LeftArm,
+    Stage::RightArm,
+    Stage::LeftLeg,
+    Stage::RightLeg,
+    Stage::LeftEye,
+    Stage::RightEye,
+    Stage::Mouth,
+];
+
+const EXTRAS: [Stage; 4] = [
+    Stage::LeftHand,
+    Stage::RightHand,
+    Stage::LeftFoot,
+    Stage::RightFoot,
+];
+
+// the most wrong guesses that can each still add something to the drawing
+pub const MAX_STAGES: usize = GALLOW.len() + PERSON.len() + EXTRAS.len();
+
+// the stages that wrong guesses reveal, in order. Up to nine lives only the
+// person is drawn, more lives add hands and feet and then build the gallow
+// up too, so every wrong guess has something to draw
+fn progression(max_wrong: usize) -> Vec<Stage> {
+    if max_wrong <= PERSON.len() {
+        PERSON.to_vec()
+    } else if max_wrong <= PERSON.len() + EXTRAS.len() {
+        PERSON
+            .iter()
+            .chain(&EXTRAS[..max_wrong - PERSON.len()])
+            .copied()
+            .collect()
+    } else {
+        GALLOW
+            .iter()
+            .chain(&PERSON)
+            .chain(&EXTRAS)
+            .copied()
+            .collect()
+    }
+}
+
+// everything to draw after `num_wrong` of `max_wrong` wrong guesses, the last
+// wrong guess always finishes the drawing
+pub fn visible(num_wrong: usize, max_wrong: usize) -> Vec<Stage> {
+    let progression = progression(max_wrong);
+    let shown = (num_wrong * progression.len())
+        .div_ceil(max_wrong.max(1))
+        .min(progression.len());
+
+    GALLOW
+        .iter()
+        .filter(|stage| !progression.contains(stage))
+        .chain(&progression[..shown])
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_miss_adds_to_the_drawing() {
+        for max_wrong in 1..=MAX_STAGES {
+            for num_wrong in 1..=max_wrong {
+                let before = visible(num_wrong - 1, max_wrong);
+                let after = visible(num_wrong, max_wrong);
+                assert!(
+                    after.len() > before.len(),
+                    "miss {} of {} drew nothing",
+                    num_wrong,
+                    max_wrong
+                );
+                assert!(before.iter().all(|stage| after.contains(stage)));
+            }
+        }
+    }
+
+    #[test]
+    fn the_last_miss_finishes_the_drawing() {
+        for max_wrong in 1..=MAX_STAGES {
+            let drawn = visible(max_wrong, max_wrong);
+            for stage in GALLOW.iter().chain(&progression(max_wrong)) {
+                assert!(
+                    drawn.contains(stage),
+                    "{:?} missing at {}",
+                    stage,
+                    max_wrong
+                );
+            }
+            assert!(drawn.contains(&Stage::Mouth));
+        }
+
+        // with a miss for every stage, all of them get drawn
+        assert_eq!(visible(MAX_STAGES, MAX_STAGES).len(), Stage::iter().count());
+    }
+}
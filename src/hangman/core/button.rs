@@ -1,12 +1,82 @@
 use macroquad::prelude::*;
 
-use crate::hangman::{TEXT_COLOR, TEXT_SIZE};
+use crate::hangman::core::text::{draw_text, measure_text, text_size_ratio};
+use crate::hangman::theme::Theme;
+
+// lines drawn over a button so it can be told apart without its color
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Pattern {
+    Plain,
+    Stripes,
+    Grid,
+}
 
 pub struct Button {
     coordinates: (f32, f32),
     dimensions: (f32, f32),
     text: String,
     color: Color,
+    pattern: Pattern,
+    // whether the keyboard is on this button, it gets a ring and enter
+    // presses it
+    focused: bool,
+}
+
+/// Which button the keyboard is on. Buttons take their turn in the order
+/// they are drawn, and the arrow keys and tab move between them.
+#[derive(Debug, Default, PartialEq)]
+pub struct Focus {
+    // the focus only shows once the keyboard is used to move it, and hides
+    // again on a click
+    shown: bool,
+    index: usize,
+    // buttons drawn so far this frame, and in the whole of the last one
+    seen: usize,
+    count: usize,
+}
+
+impl Focus {
+    // called before any button of the frame is drawn
+    pub fn start_frame(&mut self) {
+        self.count = self.seen;
+        self.seen = 0;
+        if self.count == 0 {
+            return;
+        }
+
+        let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        let tab = is_key_pressed(KeyCode::Tab);
+        let forward =
+            is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::Right) || (tab && !shift);
+        let back = is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::Left) || (tab && shift);
+        if is_mouse_button_pressed(MouseButton::Left) {
+            self.shown = false;
+        } else if (forward || back) && !self.shown {
+            // the first key press shows where the focus is without moving it
+            self.shown = true;
+        } else if forward {
+            self.index = (self.index + 1) % self.count;
+        } else if back {
+            self.index = (self.index + self.count - 1) % self.count;
+        }
+        // screens can lose buttons between frames, such as continue
+        self.index = self.index.min(self.count - 1);
+    }
+
+    // whether the next button drawn has the focus
+    pub fn next(&mut self) -> bool {
+        let focused = self.shown && self.seen == self.index;
+        self.seen += 1;
+        focused
+    }
+
+    // back to the first button for a new screen, still shown if it was
+    pub fn reset(&mut self) {
+        *self = Self {
+            shown: self.shown,
+            ..Self::default()
+        };
+    }
 }
 
 impl Button {
@@ -21,15 +91,25 @@ impl Button {
             dimensions,
             text,
             color,
+            pattern: Pattern::Plain,
+            focused: false,
         }
     }
 
-    pub fn draw(&self) {
-        let text_size_ratio = if screen_height() > screen_width() {
-            screen_width() / TEXT_SIZE
-        } else {
-            screen_height() / TEXT_SIZE
-        };
+    pub fn with_focus(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn with_pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    // `text_scale` is the text size from the settings
+    pub fn draw(&self, theme: &Theme, text_scale: f32) {
+        // bigger text settings can't make the text taller than the button
+        let text_size_ratio = text_size_ratio(text_scale).min(self.dimensions.1);
         draw_rectangle(
             self.coordinates.0,
             self.coordinates.1,
@@ -37,6 +117,17 @@ impl Button {
             self.dimensions.1,
             self.color,
         );
+        self.draw_pattern(theme);
+        if self.focused {
+            draw_rectangle_lines(
+                self.coordinates.0 - 4.0,
+                self.coordinates.1 - 4.0,
+                self.dimensions.0 + 8.0,
+                self.dimensions.1 + 8.0,
+                4.0,
+                theme.text,
+            );
+        }
         let text_size = measure_text(&self.text, None, text_size_ratio as u16, 1.0);
 
         // if the text is too big split it into two lines
@@ -50,7 +141,7 @@ impl Button {
                 self.coordinates.0 + distance - text_size.width / 2.0,
                 (self.coordinates.1 + distance) - text_size.height / 2.0,
                 text_size_ratio,
-                TEXT_COLOR,
+                theme.text,
             );
             if words.len() > 1 {
                 let text_size = measure_text(words[1], None, text_size_ratio as u16, 1.0);
@@ -59,7 +150,7 @@ impl Button {
                     self.coordinates.0 + distance - text_size.width / 2.0,
                     self.coordinates.1 + distance + text_size_ratio,
                     text_size_ratio,
-                    TEXT_COLOR,
+                    theme.text,
                 );
             }
         } else {
@@ -68,12 +159,41 @@ impl Button {
                 self.coordinates.0 + (self.dimensions.0 / 2.0 - text_size.width / 2.0),
                 self.coordinates.1 + (self.dimensions.1 / 2.0 + text_size.height / 2.0),
                 text_size_ratio,
-                TEXT_COLOR,
+                theme.text,
             );
         }
     }
 
+    fn draw_pattern(&self, theme: &Theme) {
+        let (x, y) = self.coordinates;
+        let (w, h) = self.dimensions;
+        let color = Color {
+            a: 0.25,
+            ..theme.text
+        };
+        let gap = w.min(h) / 6.0;
+
+        if matches!(self.pattern, Pattern::Stripes | Pattern::Grid) {
+            let mut line_y = y + gap;
+            while line_y < y + h {
+                draw_line(x, line_y, x + w, line_y, 2.0, color);
+                line_y += gap;
+            }
+        }
+        if self.pattern == Pattern::Grid {
+            let mut line_x = x + gap;
+            while line_x < x + w {
+                draw_line(line_x, y, line_x, y + h, 2.0, color);
+                line_x += gap;
+            }
+        }
+    }
+
+    // clicked, or enter pressed while it has the focus
     pub fn was_pressed(&self) -> Option<String> {
+        if self.focused && is_key_pressed(KeyCode::Enter) {
+            return Some(self.text.clone());
+        }
         if is_mouse_button_pressed(MouseButton::Left) {
             let (x, y) = mouse_position();
             if x >= self.coordinates.0
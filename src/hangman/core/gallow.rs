@@ -1,8 +1,9 @@
 use macroquad::prelude::*;
 
+use hangman::figure::Stage;
 use std::collections::HashMap;
 
-use crate::hangman::GALLOW_COLOR;
+use crate::hangman::theme::Theme;
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Part {
@@ -24,52 +25,62 @@ impl Gallow {
         }
     }
 
-    pub fn draw(&mut self) {
-        self.draw_down_on_right();
-        self.draw_down_on_left();
-        self.draw_bar_on_top();
-        self.draw_bar_on_bottom();
+    // every part is measured so the person and the word can be placed
+    // around it, but only the `visible` ones are drawn
+    pub fn draw(&mut self, visible: &[Stage], theme: &Theme) {
+        self.draw_down_on_right(visible.contains(&Stage::DownOnRight), theme);
+        self.draw_down_on_left(visible.contains(&Stage::DownOnLeft), theme);
+        self.draw_bar_on_top(visible.contains(&Stage::BarOnTop), theme);
+        self.draw_bar_on_bottom(visible.contains(&Stage::BarOnBottom), theme);
     }
 
-    fn draw_down_on_right(&mut self) {
+    fn draw_down_on_right(&mut self, shown: bool, theme: &Theme) {
         let x = screen_width() / 2.5;
         let y = screen_height() / 6.0;
         let w = screen_width() / 40.0;
         let h = screen_height() / 15.0;
-        draw_rectangle(x, y, w, h, GALLOW_COLOR);
+        if shown {
+            draw_rectangle(x, y, w, h, theme.gallow);
+        }
 
         self.parts
             .insert("down_on_right".to_string(), Part { x, y, w, h });
     }
 
-    pub fn draw_down_on_left(&mut self) {
+    pub fn draw_down_on_left(&mut self, shown: bool, theme: &Theme) {
         let x = screen_width() / 7.0;
         let y = screen_height() / 6.0;
         let w = screen_width() / 40.0;
         let h = screen_height() / 2.5;
-        draw_rectangle(x, y, w, h, GALLOW_COLOR);
+        if shown {
+            draw_rectangle(x, y, w, h, theme.gallow);
+        }
 
         self.parts
             .insert("down_on_left".to_string(), Part { x, y, w, h });
     }
 
-    pub fn draw_bar_on_top(&mut self) {
+    pub fn draw_bar_on_top(&mut self, shown: bool, theme: &Theme) {
         let x = screen_width() / 7.0;
         let y = screen_height() / 6.0;
         let w = screen_width() / 2.5 + screen_width() / 40.0 - screen_width() / 7.0;
         let h = screen_height() / 40.0;
-        draw_rectangle(x, y, w, h, GALLOW_COLOR);
+        if shown {
+            draw_rectangle(x, y, w, h, theme.gallow);
+        }
 
         self.parts
             .insert("bar_on_top".to_string(), Part { x, y, w, h });
     }
 
-    pub fn draw_bar_on_bottom(&mut self) {
+    pub fn draw_bar_on_bottom(&mut self, shown: bool, theme: &Theme) {
         let x = screen_width() / 7.0 - screen_width() / 20.0;
         let y = screen_height() / 6.0 + screen_height() / 2.5;
         let w = screen_width() / 40.0 + screen_width() / 10.0;
         let h = screen_height() / 40.0;
-        draw_rectangle(x, y, w, h, GALLOW_COLOR);
+        if shown {
+            draw_rectangle(x, y, w, h, theme.gallow);
+        }
 
         self.parts
             .insert("bar_on_bottom".to_string(), Part { x, y, w, h });
@@ -2,3 +2,4 @@ mod button;
 mod gallow;
 mod person;
 pub mod screen;
+pub mod text;
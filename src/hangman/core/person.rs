@@ -1,10 +1,13 @@
 use crate::hangman::core::gallow::{self, Gallow};
-use crate::hangman::HANGMAN_COLOR;
+use crate::hangman::theme::Theme;
+use hangman::figure::Stage;
 use macroquad::prelude::*;
 use std::collections::HashMap;
 
 const CHANGE_RED_TIME: f64 = 3.0;
 
+type DrawPart = fn(&mut Person, HashMap<String, gallow::Part>, &Theme);
+
 #[derive(PartialEq, Debug)]
 pub struct Part {
     x: f32,
@@ -16,7 +19,8 @@ pub struct Part {
 #[derive(PartialEq, Debug)]
 pub struct Person {
     pub parts: HashMap<String, Part>,
-    time_since_wrong: f64,
+    // when the game was lost, the face turns red a moment after
+    time_lost: f64,
     make_red: bool,
 }
 
@@ -24,32 +28,50 @@ impl Person {
     pub fn new() -> Self {
         Self {
             parts: HashMap::new(),
-            time_since_wrong: 0.0,
+            time_lost: 0.0,
             make_red: false,
         }
     }
 
-    pub fn draw(&mut self, num_wrong: usize, gallow: &Gallow) {
-        // list of function pointers
-        let draw_list = [
-            Self::draw_head,
-            Self::draw_body,
-            Self::draw_left_arm,
-            Self::draw_right_arm,
-            Self::draw_left_leg,
-            Self::draw_right_leg,
-            Self::draw_left_eye,
-            Self::draw_right_eye,
-            Self::draw_mouth,
+    // `lost` rather than the mouth being drawn decides when the face turns
+    // red, with more lives the mouth comes before the last miss
+    pub fn draw(&mut self, visible: &[Stage], lost: bool, gallow: &Gallow, theme: &Theme) {
+        if !lost {
+            self.time_lost = 0.0;
+            self.make_red = false;
+        } else if self.time_lost == 0.0 {
+            self.time_lost = get_time();
+        } else if get_time() - self.time_lost > CHANGE_RED_TIME {
+            self.make_red = true;
+        }
+
+        // list of function pointers, parts are placed relative to the ones
+        // before them so the order matters
+        let draw_list: [(Stage, DrawPart); 13] = [
+            (Stage::Head, Self::draw_head),
+            (Stage::Body, Self::draw_body),
+            (Stage::LeftArm, Self::draw_left_arm),
+            (Stage::RightArm, Self::draw_right_arm),
+            (Stage::LeftLeg, Self::draw_left_leg),
+            (Stage::RightLeg, Self::draw_right_leg),
+            (Stage::LeftEye, Self::draw_left_eye),
+            (Stage::RightEye, Self::draw_right_eye),
+            (Stage::Mouth, Self::draw_mouth),
+            (Stage::LeftHand, Self::draw_left_hand),
+            (Stage::RightHand, Self::draw_right_hand),
+            (Stage::LeftFoot, Self::draw_left_foot),
+            (Stage::RightFoot, Self::draw_right_foot),
         ];
 
-        for i in draw_list.iter().take(num_wrong) {
-            (i)(self, gallow.parts.clone());
+        for (stage, i) in draw_list.iter() {
+            if visible.contains(stage) {
+                (i)(self, gallow.parts.clone(), theme);
+            }
         }
     }
 
     // 1
-    fn draw_head(&mut self, parts: HashMap<String, gallow::Part>) {
+    fn draw_head(&mut self, parts: HashMap<String, gallow::Part>, theme: &Theme) {
         let top_of_gallow = parts.get("down_on_right").unwrap();
         let w = if screen_width() > screen_height() {
             screen_height() / 20.0
@@ -59,13 +81,13 @@ impl Person {
         let x = top_of_gallow.x + top_of_gallow.w / 2.0;
         let y = top_of_gallow.y + top_of_gallow.h + w / 2.0;
 
-        draw_circle(x, y, w, HANGMAN_COLOR);
+        draw_circle(x, y, w, theme.person);
         self.parts
             .insert("head".to_string(), Part { x, y, w, h: w });
     }
 
     // 2
-    fn draw_body(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_body(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         let head = self.parts.get("head").unwrap();
 
         let x = head.x - head.w / 4.0;
@@ -73,12 +95,12 @@ impl Person {
         let w = head.w / 2.0;
         let h = head.w * 2.0;
 
-        draw_rectangle(x, y, w, h, HANGMAN_COLOR);
+        draw_rectangle(x, y, w, h, theme.person);
         self.parts.insert("body".to_string(), Part { x, y, w, h });
     }
 
     // 3
-    fn draw_left_arm(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_left_arm(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the left
         let body = self.parts.get("body").unwrap();
 
@@ -87,13 +109,13 @@ impl Person {
         let w = body.w;
         let h = body.h / 2.0;
 
-        draw_rectangle(x, y, w, h, HANGMAN_COLOR);
+        draw_rectangle(x, y, w, h, theme.person);
         self.parts
             .insert("left_arm".to_string(), Part { x, y, w, h });
     }
 
     // 4
-    fn draw_right_arm(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_right_arm(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the right
         let body = self.parts.get("body").unwrap();
 
@@ -102,13 +124,13 @@ impl Person {
         let w = body.w;
         let h = body.h / 2.0;
 
-        draw_rectangle(x, y, w, h, HANGMAN_COLOR);
+        draw_rectangle(x, y, w, h, theme.person);
         self.parts
             .insert("right_arm".to_string(), Part { x, y, w, h });
     }
 
     // 5
-    fn draw_left_leg(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_left_leg(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the left
         let body = self.parts.get("body").unwrap();
 
@@ -117,13 +139,13 @@ impl Person {
         let w = body.w / 2.0;
         let h = body.h / 2.0;
 
-        draw_rectangle(x, y, w, h, HANGMAN_COLOR);
+        draw_rectangle(x, y, w, h, theme.person);
         self.parts
             .insert("left_leg".to_string(), Part { x, y, w, h });
     }
 
     // 6
-    fn draw_right_leg(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_right_leg(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the right
         let body = self.parts.get("body").unwrap();
 
@@ -132,13 +154,13 @@ impl Person {
         let w = body.w / 2.0;
         let h = body.h / 2.0;
 
-        draw_rectangle(x, y, w, h, HANGMAN_COLOR);
+        draw_rectangle(x, y, w, h, theme.person);
         self.parts
             .insert("right_leg".to_string(), Part { x, y, w, h });
     }
 
     // 7
-    fn draw_left_eye(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_left_eye(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the left
         let head = self.parts.get("head").unwrap();
 
@@ -148,9 +170,9 @@ impl Person {
         let h = head.h / 2.0;
 
         if self.make_red {
-            draw_circle(x, y + w / 2.0, w / 2.0, RED);
+            draw_circle(x, y + w / 2.0, w / 2.0, theme.dead);
         } else {
-            draw_circle(x, y + w / 2.0, w / 2.0, WHITE);
+            draw_circle(x, y + w / 2.0, w / 2.0, theme.eyes);
         }
 
         self.parts
@@ -158,7 +180,7 @@ impl Person {
     }
 
     // 8
-    fn draw_right_eye(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_right_eye(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a rectangle from the body to the right
         let head = self.parts.get("head").unwrap();
 
@@ -168,9 +190,9 @@ impl Person {
         let h = head.h / 2.0;
 
         if self.make_red {
-            draw_circle(x, y + w / 2.0, w / 2.0, RED);
+            draw_circle(x, y + w / 2.0, w / 2.0, theme.dead);
         } else {
-            draw_circle(x, y + w / 2.0, w / 2.0, WHITE);
+            draw_circle(x, y + w / 2.0, w / 2.0, theme.eyes);
         }
 
         self.parts
@@ -178,20 +200,17 @@ impl Person {
     }
 
     // 9
-    fn draw_mouth(&mut self, _parts: HashMap<String, gallow::Part>) {
+    fn draw_mouth(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
         // draw a mouth by drawing 2 circles offset from the head
         let head = self.parts.get("head").unwrap();
 
         let x = head.x;
         let y = head.y + head.h / 2.0;
         let w = head.w / 3.0;
-        if self.time_since_wrong == 0.0 {
-            self.time_since_wrong = get_time();
-        } else if get_time() - self.time_since_wrong > CHANGE_RED_TIME {
-            draw_circle(x, y, w, RED);
-            self.make_red = true;
+        if self.make_red {
+            draw_circle(x, y, w, theme.dead);
         } else {
-            draw_circle(x, y, w, WHITE);
+            draw_circle(x, y, w, theme.eyes);
         }
 
         let y = head.y + head.h / 2.0 - w / 2.0;
@@ -199,6 +218,64 @@ impl Person {
 
         self.parts
             .insert("mouth".to_string(), Part { x, y, w, h: w });
-        draw_circle(x, y, w, HANGMAN_COLOR);
+        draw_circle(x, y, w, theme.person);
+    }
+
+    // 10
+    fn draw_left_hand(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
+        // draw a circle at the end of the left arm
+        let arm = self.parts.get("left_arm").unwrap();
+
+        let x = arm.x + arm.w / 2.0;
+        let y = arm.y + arm.h;
+        let w = arm.w / 1.5;
+
+        draw_circle(x, y, w, theme.person);
+        self.parts
+            .insert("left_hand".to_string(), Part { x, y, w, h: w });
+    }
+
+    // 11
+    fn draw_right_hand(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
+        // draw a circle at the end of the right arm
+        let arm = self.parts.get("right_arm").unwrap();
+
+        let x = arm.x + arm.w / 2.0;
+        let y = arm.y + arm.h;
+        let w = arm.w / 1.5;
+
+        draw_circle(x, y, w, theme.person);
+        self.parts
+            .insert("right_hand".to_string(), Part { x, y, w, h: w });
+    }
+
+    // 12
+    fn draw_left_foot(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
+        // draw a rectangle from the bottom of the left leg to the left
+        let leg = self.parts.get("left_leg").unwrap();
+
+        let x = leg.x - leg.w;
+        let y = leg.y + leg.h;
+        let w = leg.w * 2.0;
+        let h = leg.w / 1.5;
+
+        draw_rectangle(x, y, w, h, theme.person);
+        self.parts
+            .insert("left_foot".to_string(), Part { x, y, w, h });
+    }
+
+    // 13
+    fn draw_right_foot(&mut self, _parts: HashMap<String, gallow::Part>, theme: &Theme) {
+        // draw a rectangle from the bottom of the right leg to the right
+        let leg = self.parts.get("right_leg").unwrap();
+
+        let x = leg.x;
+        let y = leg.y + leg.h;
+        let w = leg.w * 2.0;
+        let h = leg.w / 1.5;
+
+        draw_rectangle(x, y, w, h, theme.person);
+        self.parts
+            .insert("right_foot".to_string(), Part { x, y, w, h });
     }
 }
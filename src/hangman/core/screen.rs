@@ -1,13 +1,19 @@
 use std::collections::HashSet;
 
+use hangman::engine::{next_lives, GameMode, MAX_WRONG};
+use hangman::figure::{Stage, MAX_STAGES};
+use hangman::layout::Layout;
+use hangman::pack::Pack;
+use hangman::settings::Settings;
+use hangman::stats::Stats;
 use macroquad::prelude::*;
 use strum::IntoEnumIterator;
 
-use crate::hangman::{
-    Difficulty, BACKGROUND_COLOR, BUTTON_GRAY, BUTTON_RED, TEXT_COLOR, TEXT_SIZE, TITLE_TEXT,
-};
+use crate::hangman::theme::Theme;
+use crate::hangman::{AsColor, Difficulty, TITLE_TEXT};
 
-use super::button::Button;
+use super::button::{Button, Focus, Pattern};
+use super::text::{draw_text, measure_text, text_size_ratio, TEXT_SCALES};
 use crate::hangman::core::gallow::Gallow;
 use crate::hangman::core::person::Person;
 
@@ -16,32 +22,140 @@ pub struct Screen {
     person: Person,
     gallow: Gallow,
     pub screen_type: ScreenType,
+    pub mode: GameMode,
+    pub max_wrong: usize,
+    // the pack new games pick from, `None` for the plain word lists
+    pub category: Option<usize>,
+    pub layout: Layout,
+    pub theme: Theme,
+    // marks that don't go by color alone, for players who can't tell the
+    // colors apart
+    pub cues: bool,
+    pub sound: bool,
+    // how much bigger than normal text is drawn, one of TEXT_SCALES
+    pub text_scale: f32,
+    // the difficulty a game starts with at launch, `None` for this screen
+    pub default_difficulty: Option<Difficulty>,
+    // whether the pause menu is asking to make sure about quitting
+    confirm_quit: bool,
+    focus: Focus,
+    // the screen the focus belongs to
+    focus_screen: ScreenType,
 }
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub enum ScreenType {
     Start,
+    SecretWord,
+    Statistics,
+    Categories,
+    Settings,
     Main,
+    Paused,
     End,
 }
 
+#[derive(Eq, PartialEq, Debug)]
+pub enum StartChoice {
+    Difficulty(Difficulty),
+    Daily,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum EndAction {
+    PlayAgain,
+    Share,
+    Quit,
+}
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum PauseAction {
+    Resume,
+    Restart,
+    Menu,
+    Quit,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum SecretWordAction {
+    Submit,
+    ToggleDictionary,
+}
+
 impl Screen {
     pub fn new() -> Self {
         Self {
             person: Person::new(),
             gallow: Gallow::new(),
             screen_type: ScreenType::Start,
+            mode: GameMode::Normal,
+            max_wrong: MAX_WRONG,
+            category: None,
+            layout: Layout::default(),
+            theme: Theme::default(),
+            cues: false,
+            sound: true,
+            text_scale: TEXT_SCALES[0],
+            default_difficulty: None,
+            confirm_quit: false,
+            focus: Focus::default(),
+            focus_screen: ScreenType::Start,
         }
     }
 
-    pub fn get_difficulty(&self) -> Option<Difficulty> {
-        clear_background(BACKGROUND_COLOR);
+    // what is kept for the next launch
+    pub fn settings(&self) -> Settings {
+        Settings {
+            theme: self.theme.name.clone(),
+            layout: self.layout,
+            max_wrong: self.max_wrong,
+            sound: self.sound,
+            text_scale: self.text_scale,
+            cues: self.cues,
+            difficulty: self.default_difficulty,
+        }
+    }
 
-        let text_size_ratio = if screen_height() > screen_width() {
-            screen_width() / TEXT_SIZE
-        } else {
-            screen_height() / TEXT_SIZE
-        };
+    // picks the settings from the last launch back up, `themes` is every
+    // theme that can be picked from
+    pub fn apply(&mut self, settings: &Settings, themes: &[Theme]) {
+        match themes.iter().find(|theme| theme.name == settings.theme) {
+            Some(theme) => self.theme = theme.clone(),
+            None => eprintln!("unknown theme {}, using dark", settings.theme),
+        }
+        self.layout = settings.layout;
+        if (1..=MAX_STAGES).contains(&settings.max_wrong) {
+            self.max_wrong = settings.max_wrong;
+        }
+        self.sound = settings.sound;
+        if TEXT_SCALES.contains(&settings.text_scale) {
+            self.text_scale = settings.text_scale;
+        }
+        self.cues = settings.cues;
+        self.default_difficulty = settings.difficulty;
+    }
+
+    // called every frame before anything is drawn, a new screen starts with
+    // the focus on its first button
+    pub fn start_frame(&mut self) {
+        if self.focus_screen != self.screen_type {
+            self.focus.reset();
+            self.focus_screen = self.screen_type;
+        }
+        self.focus.start_frame();
+    }
+
+    // back to the start screen with a fresh drawing, keeping what was picked
+    pub fn play_again(&mut self) {
+        self.person = Person::new();
+        self.gallow = Gallow::new();
+        self.screen_type = ScreenType::Start;
+    }
+
+    pub fn get_difficulty(&mut self, can_continue: bool, category: &str) -> Option<StartChoice> {
+        clear_background(self.theme.background);
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
 
         for (i, text) in TITLE_TEXT.iter().enumerate() {
             let text_size = measure_text(text, None, text_size_ratio as u16, 1.0);
@@ -52,10 +166,31 @@ impl Screen {
                 (screen_height() / 2.0 - text_size.height / 2.0) - 3.0 * text_size_ratio
                     + (i as f32 * text_size_ratio),
                 text_size_ratio,
-                TEXT_COLOR,
+                self.theme.text,
             );
         }
 
+        // an unfinished game gets a button in the empty space left of the
+        // difficulties
+        if can_continue {
+            let spacing = screen_width() / 5.0;
+            let gap = 30.0;
+            let w = spacing - gap;
+
+            let button = Button::new(
+                (gap / 2.0, screen_height() / 2.0),
+                (w, w),
+                "continue".to_string(),
+                self.theme.button,
+            )
+            .with_focus(self.focus.next());
+            button.draw(&self.theme, self.text_scale);
+
+            if button.was_pressed().is_some() {
+                self.screen_type = ScreenType::Main;
+            }
+        }
+
         for (i, difficulty) in Difficulty::iter().enumerate() {
             let spacing = screen_width() / 5.0;
             let gap = 30.0;
@@ -63,33 +198,618 @@ impl Screen {
             let y = screen_height() / 2.0;
             let w = spacing - gap;
 
-            let button = Button::new(
+            let mut button = Button::new(
                 (x, y),
                 (w, w),
                 difficulty.as_str().to_string(),
-                difficulty.as_color(),
-            );
-            button.draw();
+                difficulty.as_color(&self.theme),
+            )
+            .with_focus(self.focus.next());
+            if self.cues {
+                button = button.with_pattern(match difficulty {
+                    Difficulty::Easy => Pattern::Plain,
+                    Difficulty::Medium => Pattern::Stripes,
+                    Difficulty::Hard => Pattern::Grid,
+                });
+            }
+            button.draw(&self.theme, self.text_scale);
 
             if let Some(x) = button.was_pressed() {
-                return Some(Difficulty::from_string(x.as_str()));
+                return Some(StartChoice::Difficulty(Difficulty::from_string(x.as_str())));
             }
         }
 
+        // the daily word fills the space right of the difficulties
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let w = spacing - gap;
+
+        let button = Button::new(
+            (spacing * 4.0 + gap / 2.0, screen_height() / 2.0),
+            (w, w),
+            "daily".to_string(),
+            self.theme.button,
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            return Some(StartChoice::Daily);
+        }
+
+        let button = self.menu_button(0, category.to_string());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.screen_type = ScreenType::Categories;
+        }
+
+        // clicking the mode button cycles through the game modes
+        let button = self.menu_button(1, format!("{} mode", self.mode.as_str()));
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.mode = next(self.mode);
+        }
+
+        let button = self.menu_button(2, "two player".to_string());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.screen_type = ScreenType::SecretWord;
+        }
+
+        let button = self.menu_button(3, "statistics".to_string());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.screen_type = ScreenType::Statistics;
+        }
+
+        // clicking the lives button moves on to the next bigger choice
+        let button = self.menu_button(4, format!("{} lives", self.max_wrong));
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.max_wrong = next_lives(self.max_wrong);
+        }
+
+        // settings sit in the top right corner, out of the way of the title
+        let button = Button::new(
+            (screen_width() - w - gap / 2.0, gap / 2.0),
+            (w, w / 2.0),
+            "settings".to_string(),
+            self.theme.button,
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            self.screen_type = ScreenType::Settings;
+        }
+
+        None
+    }
+
+    // the smaller buttons in the row under the difficulties, the outer
+    // columns line up with the continue and daily buttons
+    fn menu_button(&mut self, column: usize, text: String) -> Button {
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let w = spacing - gap;
+        let x = spacing * column as f32 + (gap / 2.0);
+        let y = screen_height() / 2.0 + w + gap;
+
+        Button::new((x, y), (w, w / 2.0), text, self.theme.button).with_focus(self.focus.next())
+    }
+
+    pub fn get_secret_word(
+        &mut self,
+        secret_word: &str,
+        message: Option<&str>,
+        check_dictionary: bool,
+    ) -> Option<SecretWordAction> {
+        clear_background(self.theme.background);
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        // the word is hidden so player two can't read it over their shoulder
+        let hidden = "*".repeat(secret_word.chars().count());
+        let lines = [
+            "Player one, type a secret word.",
+            "Press enter when you are done.",
+            hidden.as_str(),
+            message.unwrap_or(""),
+        ];
+
+        for (i, text) in lines.iter().enumerate() {
+            let text_size = measure_text(text, None, text_size_ratio as u16, 1.0);
+
+            draw_text(
+                text,
+                screen_width() / 2.0 - text_size.width / 2.0,
+                (screen_height() / 2.0 - text_size.height / 2.0) - 5.0 * text_size_ratio
+                    + (i as f32 * text_size_ratio),
+                text_size_ratio,
+                self.theme.text,
+            );
+        }
+
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let x = spacing * 1.0 + (gap / 2.0);
+        let y = screen_height() / 2.0;
+        let w = spacing - gap;
+
+        let button = Button::new(
+            (x, y),
+            (w, w),
+            "Start Game".to_string(),
+            Difficulty::Easy.as_color(&self.theme),
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            return Some(SecretWordAction::Submit);
+        }
+
+        let x = spacing * 3.0 + (gap / 2.0);
+
+        let button = Button::new(
+            (x, y),
+            (w, w),
+            if check_dictionary {
+                "Dictionary Words".to_string()
+            } else {
+                "Any Word".to_string()
+            },
+            self.theme.button,
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() {
+            return Some(SecretWordAction::ToggleDictionary);
+        }
+
         None
     }
 
-    pub fn draw_gallow(&mut self) {
-        self.gallow.draw();
+    // returns true when the back button is pressed
+    pub fn draw_statistics(&mut self, stats: &Stats) -> bool {
+        clear_background(self.theme.background);
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let mut lines = vec![
+            "Statistics".to_string(),
+            format!(
+                "played: {}   won: {}   lost: {}",
+                stats.games_played,
+                stats.wins(),
+                stats.losses()
+            ),
+            format!(
+                "streak: {}   best streak: {}   average wrong: {:.1}",
+                stats.current_streak,
+                stats.best_streak,
+                stats.average_wrong()
+            ),
+        ];
+        for difficulty in Difficulty::iter() {
+            let record = stats
+                .difficulties
+                .get(&difficulty)
+                .cloned()
+                .unwrap_or_default();
+            lines.push(format!(
+                "{}: {} won, {} lost",
+                difficulty.as_str(),
+                record.wins,
+                record.losses
+            ));
+        }
+        if !stats.recent.is_empty() {
+            lines.push("recent words:".to_string());
+            let recent: Vec<String> = stats
+                .recent
+                .iter()
+                .map(|game| {
+                    let hints = match game.hints {
+                        0 => String::new(),
+                        1 => " (1 hint)".to_string(),
+                        n => format!(" ({} hints)", n),
+                    };
+                    format!(
+                        "{}{}{}",
+                        game.word,
+                        if game.won { "" } else { " (lost)" },
+                        hints
+                    )
+                })
+                .collect();
+            for chunk in recent.chunks(5) {
+                lines.push(chunk.join(", "));
+            }
+        }
+
+        for (i, text) in lines.iter().enumerate() {
+            let text_size = measure_text(text, None, text_size_ratio as u16, 1.0);
+
+            draw_text(
+                text,
+                screen_width() / 2.0 - text_size.width / 2.0,
+                screen_height() / 10.0 + (i as f32 * text_size_ratio * 1.2),
+                text_size_ratio,
+                self.theme.text,
+            );
+        }
+
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let w = spacing - gap;
+        let x = screen_width() / 2.0 - w / 2.0;
+        let y = screen_height() - w / 2.0 - gap;
+
+        let button = Button::new((x, y), (w, w / 2.0), "back".to_string(), self.theme.button)
+            .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        button.was_pressed().is_some() || is_key_pressed(KeyCode::Escape)
+    }
+
+    // lists the plain words and every pack to pick from, the chosen one in
+    // red, and goes back to the start screen once one is picked
+    pub fn get_category(&mut self, packs: &[Pack]) {
+        clear_background(self.theme.background);
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let text = "Categories";
+        let text_size = measure_text(text, None, text_size_ratio as u16, 1.0);
+        draw_text(
+            text,
+            screen_width() / 2.0 - text_size.width / 2.0,
+            screen_height() / 10.0,
+            text_size_ratio,
+            self.theme.text,
+        );
+
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let back_w = spacing - gap;
+
+        // the rows shrink to fit above the back button
+        let top = screen_height() / 10.0 + text_size_ratio;
+        let bottom = screen_height() - back_w / 2.0 - gap * 2.0;
+        let choices: Vec<(&str, &str)> = std::iter::once(("words", "the standard word lists"))
+            .chain(
+                packs
+                    .iter()
+                    .map(|pack| (pack.name.as_str(), pack.description.as_str())),
+            )
+            .collect();
+        let row = ((bottom - top) / choices.len() as f32).min(text_size_ratio * 2.0);
+
+        let x = screen_width() / 10.0;
+        let w = screen_width() / 4.0;
+        for (i, (name, description)) in choices.iter().enumerate() {
+            let category = i.checked_sub(1);
+            let y = top + i as f32 * row;
+            let color = if category == self.category {
+                self.theme.selected
+            } else {
+                self.theme.button
+            };
+
+            let name = if self.cues && category == self.category {
+                format!("✓ {}", name)
+            } else {
+                name.to_string()
+            };
+            let button =
+                Button::new((x, y), (w, row - 8.0), name, color).with_focus(self.focus.next());
+            button.draw(&self.theme, self.text_scale);
+
+            let text_size = measure_text(description, None, text_size_ratio as u16, 1.0);
+            draw_text(
+                description,
+                x + w + gap,
+                y + (row - 8.0) / 2.0 + text_size.height / 2.0,
+                text_size_ratio,
+                self.theme.text,
+            );
+
+            if button.was_pressed().is_some() {
+                self.category = category;
+                self.screen_type = ScreenType::Start;
+            }
+        }
+
+        let x = screen_width() / 2.0 - back_w / 2.0;
+        let y = screen_height() - back_w / 2.0 - gap;
+
+        let button = Button::new(
+            (x, y),
+            (back_w, back_w / 2.0),
+            "back".to_string(),
+            self.theme.button,
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() || is_key_pressed(KeyCode::Escape) {
+            self.screen_type = ScreenType::Start;
+        }
     }
 
-    pub fn draw_keyboard(&self, letters: &HashSet<char>) -> Option<char> {
-        let characters = [
-            vec!['q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p'],
-            vec!['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'],
-            vec!['z', 'x', 'c', 'v', 'b', 'n', 'm'],
+    // every setting on a row of its own, clicking its button moves on to the
+    // next choice
+    pub fn get_settings(&mut self, themes: &[Theme]) {
+        clear_background(self.theme.background);
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let text = "Settings";
+        let text_size = measure_text(text, None, text_size_ratio as u16, 1.0);
+        draw_text(
+            text,
+            screen_width() / 2.0 - text_size.width / 2.0,
+            screen_height() / 10.0,
+            text_size_ratio,
+            self.theme.text,
+        );
+
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let back_w = spacing - gap;
+
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        let settings = [
+            ("theme", self.theme.name.clone()),
+            ("keyboard layout", self.layout.as_str().to_string()),
+            ("lives", self.max_wrong.to_string()),
+            ("sound", on_off(self.sound)),
+            (
+                "text size",
+                format!("{}%", (self.text_scale * 100.0).round()),
+            ),
+            ("color cues", on_off(self.cues)),
+            (
+                "start with",
+                self.default_difficulty
+                    .map_or("start screen".to_string(), |difficulty| {
+                        difficulty.as_str().to_string()
+                    }),
+            ),
         ];
 
+        // the rows shrink to fit above the back button
+        let top = screen_height() / 10.0 + text_size_ratio;
+        let bottom = screen_height() - back_w / 2.0 - gap * 2.0;
+        let row = ((bottom - top) / settings.len() as f32).min(text_size_ratio * 2.0);
+        let x = screen_width() / 2.0 + gap / 2.0;
+        let w = screen_width() / 4.0;
+        for (i, (name, value)) in settings.iter().enumerate() {
+            let y = top + i as f32 * row;
+
+            let text_size = measure_text(name, None, text_size_ratio as u16, 1.0);
+            draw_text(
+                name,
+                screen_width() / 2.0 - gap / 2.0 - text_size.width,
+                y + (row - 8.0) / 2.0 + text_size.height / 2.0,
+                text_size_ratio,
+                self.theme.text,
+            );
+
+            let button = Button::new((x, y), (w, row - 8.0), value.clone(), self.theme.button)
+                .with_focus(self.focus.next());
+            button.draw(&self.theme, self.text_scale);
+
+            if button.was_pressed().is_some() {
+                match i {
+                    0 => {
+                        let i = themes.iter().position(|theme| *theme == self.theme);
+                        let next = i.map_or(0, |i| (i + 1) % themes.len());
+                        if let Some(theme) = themes.get(next) {
+                            self.theme = theme.clone();
+                        }
+                    }
+                    1 => self.layout = next(self.layout),
+                    2 => self.max_wrong = next_lives(self.max_wrong),
+                    3 => self.sound = !self.sound,
+                    4 => {
+                        self.text_scale = TEXT_SCALES
+                            .iter()
+                            .copied()
+                            .find(|scale| *scale > self.text_scale)
+                            .unwrap_or(TEXT_SCALES[0]);
+                    }
+                    5 => self.cues = !self.cues,
+                    6 => {
+                        // the start screen comes after the last difficulty
+                        let difficulties: Vec<Difficulty> = Difficulty::iter().collect();
+                        self.default_difficulty = match self.default_difficulty {
+                            None => difficulties.first().copied(),
+                            Some(difficulty) => difficulties
+                                .iter()
+                                .position(|d| *d == difficulty)
+                                .and_then(|i| difficulties.get(i + 1))
+                                .copied(),
+                        };
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let x = screen_width() / 2.0 - back_w / 2.0;
+        let y = screen_height() - back_w / 2.0 - gap;
+
+        let button = Button::new(
+            (x, y),
+            (back_w, back_w / 2.0),
+            "back".to_string(),
+            self.theme.button,
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if button.was_pressed().is_some() || is_key_pressed(KeyCode::Escape) {
+            self.screen_type = ScreenType::Start;
+        }
+    }
+
+    // the pause menu over the game, quitting has to be picked twice. Escape
+    // resumes, or backs out of quitting
+    pub fn draw_pause(&mut self) -> Option<PauseAction> {
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color {
+                a: 0.85,
+                ..self.theme.background
+            },
+        );
+
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let (title, choices): (&str, &[(&str, Option<PauseAction>)]) = if self.confirm_quit {
+            (
+                "Quit? Your game is saved.",
+                &[("quit", Some(PauseAction::Quit)), ("cancel", None)],
+            )
+        } else {
+            (
+                "Paused",
+                &[
+                    ("resume", Some(PauseAction::Resume)),
+                    ("restart", Some(PauseAction::Restart)),
+                    ("menu", Some(PauseAction::Menu)),
+                    ("quit", None),
+                ],
+            )
+        };
+
+        let text_size = measure_text(title, None, text_size_ratio as u16, 1.0);
+        let y = screen_height() / 3.0;
+        draw_text(
+            title,
+            screen_width() / 2.0 - text_size.width / 2.0,
+            y,
+            text_size_ratio,
+            self.theme.text,
+        );
+
+        let w = screen_width() / 4.0;
+        let h = text_size_ratio * 2.0;
+        let x = screen_width() / 2.0 - w / 2.0;
+        for (i, (text, action)) in choices.iter().enumerate() {
+            let y = y + text_size_ratio + i as f32 * (h + 10.0);
+            let button = Button::new((x, y), (w, h), text.to_string(), self.theme.button)
+                .with_focus(self.focus.next());
+            button.draw(&self.theme, self.text_scale);
+
+            if button.was_pressed().is_some() {
+                // the first quit and cancel go between asking and the menu
+                if action.is_none() {
+                    self.confirm_quit = !self.confirm_quit;
+                    self.focus.reset();
+                    return None;
+                }
+                self.confirm_quit = false;
+                return *action;
+            }
+        }
+
+        if is_key_pressed(KeyCode::Escape) {
+            if self.confirm_quit {
+                self.confirm_quit = false;
+                self.focus.reset();
+            } else {
+                return Some(PauseAction::Resume);
+            }
+        }
+
+        None
+    }
+
+    // the hint button on the right of the gallow, and the clue under it once
+    // a hint has given it away. Returns true when the button is pressed
+    pub fn draw_hint(&mut self, clue: Option<&str>, can_hint: bool) -> bool {
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let spacing = screen_width() / 5.0;
+        let gap = 30.0;
+        let w = spacing - gap;
+        let x = spacing * 4.0 + gap / 2.0;
+        let y = screen_height() / 6.0;
+
+        // the button grays out when a hint would cost the last life
+        let color = if can_hint {
+            self.theme.selected
+        } else {
+            self.theme.button
+        };
+        let text = if self.cues && !can_hint {
+            "no hint"
+        } else {
+            "Hint"
+        };
+        let button = Button::new((x, y), (w, w / 2.0), text.to_string(), color)
+            .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
+
+        if let Some(clue) = clue {
+            let x = screen_width() / 2.0;
+            let lines = wrap_text(clue, text_size_ratio, screen_width() / 2.0 - gap);
+            for (i, line) in lines.iter().enumerate() {
+                draw_text(
+                    line,
+                    x,
+                    y + w / 2.0 + gap + (i as f32 + 1.0) * text_size_ratio,
+                    text_size_ratio,
+                    self.theme.text,
+                );
+            }
+        }
+
+        can_hint && button.was_pressed().is_some()
+    }
+
+    // the category of the game being played, shown above the gallow as a hint
+    pub fn draw_category(&self, category: &str) {
+        let text_size_ratio = text_size_ratio(self.text_scale);
+
+        let text = format!("category: {}", category);
+        let text_size = measure_text(&text, None, text_size_ratio as u16, 1.0);
+
+        draw_text(
+            &text,
+            screen_width() / 2.0 - text_size.width / 2.0,
+            screen_height() / 10.0,
+            text_size_ratio,
+            self.theme.text,
+        );
+    }
+
+    pub fn draw_gallow(&mut self, visible: &[Stage]) {
+        self.gallow.draw(visible, &self.theme);
+    }
+
+    // `keyboard` is the rows of keys from the game's alphabet, `letters` the
+    // ones not guessed yet and `wrong` the guessed ones that aren't in the word
+    pub fn draw_keyboard(
+        &self,
+        keyboard: &[Vec<char>],
+        letters: &HashSet<char>,
+        wrong: &[char],
+    ) -> Option<char> {
+        let longest_row = keyboard.iter().map(Vec::len).max().unwrap_or(0);
+
         // gallow y and h bottom cords
         let y = screen_height() / 6.0 + screen_height() / 2.5;
         let h = screen_height() / 40.0;
@@ -97,13 +817,15 @@ impl Screen {
         let top_gap = (gallow_height / 4.0) / 2.0;
         let spacing = 1.5;
 
-        for (o, row) in characters.iter().enumerate() {
+        for (o, row) in keyboard.iter().enumerate() {
             for (i, c) in row.iter().enumerate() {
                 let w = if screen_height() > screen_width() {
                     screen_width() / 15.0
                 } else {
                     screen_height() / 15.0
                 };
+                // alphabets with more letters get smaller keys to fit
+                let w = w.min(screen_width() / (longest_row as f32 * spacing + 1.0));
                 let x = (screen_width() / 2.0) - (w * row.len() as f32 / 2.0 * spacing)
                     + (w * (i as f32 + 0.14) * spacing);
                 let y = top_gap + gallow_height + (o as f32 * w) + (o as f32 * top_gap / 4.0);
@@ -113,12 +835,26 @@ impl Screen {
                     (w, w),
                     c.to_string(),
                     if letters.contains(c) {
-                        BUTTON_RED
+                        self.theme.selected
                     } else {
-                        BUTTON_GRAY
+                        self.theme.button
                     },
                 );
-                button.draw();
+                button.draw(&self.theme, self.text_scale);
+
+                // used keys are struck through and marked right or wrong
+                if self.cues && !letters.contains(c) {
+                    draw_line(
+                        x + w * 0.15,
+                        y + w * 0.5,
+                        x + w * 0.85,
+                        y + w * 0.5,
+                        2.0,
+                        self.theme.text,
+                    );
+                    let mark = if wrong.contains(c) { "✗" } else { "✓" };
+                    draw_text(mark, x + w * 0.7, y + w * 0.35, w / 3.0, self.theme.text);
+                }
 
                 if let Some(c) = button.was_pressed() {
                     return c.chars().next();
@@ -128,42 +864,37 @@ impl Screen {
         None
     }
 
-    pub fn draw_word(&self, guess: &[Option<char>], word: String) {
+    pub fn draw_word(&self, text: &str) {
         let bottom_of_gallow = self.gallow.parts.get("bar_on_bottom").unwrap();
         let y = bottom_of_gallow.y + bottom_of_gallow.h + 10.0;
 
-        let text_size_ratio = if screen_height() > screen_width() {
-            screen_width() / TEXT_SIZE
-        } else {
-            screen_height() / TEXT_SIZE
-        };
+        let text_size_ratio = text_size_ratio(self.text_scale);
 
-        let mut text = String::new();
-        for c in word.chars() {
-            if guess.contains(&Some(c)) {
-                text.push(c);
-            } else {
-                text.push('_');
-            }
-        }
+        // phrases too wide for the screen wrap between their words
+        let lines = wrap_text(text, text_size_ratio, screen_width() * 0.9);
 
-        let text_size = measure_text(&text, None, text_size_ratio as u16, 1.0);
+        for (i, line) in lines.iter().enumerate() {
+            let text_size = measure_text(line, None, text_size_ratio as u16, 1.0);
 
-        draw_text(
-            &text,
-            screen_width() / 2.0 - text_size.width / 2.0,
-            y,
-            text_size_ratio,
-            TEXT_COLOR,
-        );
+            draw_text(
+                line,
+                screen_width() / 2.0 - text_size.width / 2.0,
+                y + i as f32 * text_size_ratio,
+                text_size_ratio,
+                self.theme.text,
+            );
+        }
     }
 
-    pub fn draw_end_screen(&self, did_win: bool) -> bool {
-        let text_size_ratio = if screen_height() > screen_width() {
-            screen_width() / TEXT_SIZE
-        } else {
-            screen_height() / TEXT_SIZE
-        };
+    // `share` is the label of the share button, which only daily games have
+    pub fn draw_end_screen(
+        &mut self,
+        did_win: bool,
+        seed: Option<u64>,
+        hints: usize,
+        share: Option<&str>,
+    ) -> Option<EndAction> {
+        let text_size_ratio = text_size_ratio(self.text_scale);
 
         let text = "Game Over!";
         let text_size = measure_text(text, None, (text_size_ratio * 4.0) as u16, 1.0);
@@ -173,7 +904,7 @@ impl Screen {
             screen_width() / 2.0 - text_size.width / 2.0,
             (screen_height() / 2.0 - text_size.height / 2.0) - 3.0 * text_size_ratio,
             text_size_ratio * 4.0,
-            TEXT_COLOR,
+            self.theme.text,
         );
 
         let text = if did_win { "You Won!" } else { "You Lost!" };
@@ -185,9 +916,36 @@ impl Screen {
             (screen_height() / 2.0 - text_size.height / 2.0) - 3.0 * text_size_ratio
                 + text_size_ratio * 2.0,
             text_size_ratio * 3.0,
-            TEXT_COLOR,
+            self.theme.text,
         );
 
+        // the seed lets a game be played again with --seed
+        if let Some(seed) = seed {
+            let text = format!("seed: {}", seed);
+            let text_size = measure_text(&text, None, text_size_ratio as u16, 1.0);
+
+            draw_text(
+                &text,
+                screen_width() / 2.0 - text_size.width / 2.0,
+                screen_height() - text_size_ratio,
+                text_size_ratio,
+                self.theme.text,
+            );
+        }
+
+        if hints > 0 {
+            let text = format!("hints used: {}", hints);
+            let text_size = measure_text(&text, None, text_size_ratio as u16, 1.0);
+
+            draw_text(
+                &text,
+                screen_width() / 2.0 - text_size.width / 2.0,
+                screen_height() - text_size_ratio * 2.0,
+                text_size_ratio,
+                self.theme.text,
+            );
+        }
+
         let spacing = screen_width() / 5.0;
         let gap = 30.0;
         let x = spacing * 1.0 + (gap / 2.0);
@@ -198,12 +956,25 @@ impl Screen {
             (x, y),
             (w, w),
             "Play Again".to_string(),
-            Difficulty::Easy.as_color(),
-        );
-        button.draw();
+            Difficulty::Easy.as_color(&self.theme),
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
 
         if button.was_pressed().is_some() {
-            return true;
+            return Some(EndAction::PlayAgain);
+        }
+
+        if let Some(share) = share {
+            let x = spacing * 2.0 + (gap / 2.0);
+
+            let button = Button::new((x, y), (w, w), share.to_string(), self.theme.button)
+                .with_focus(self.focus.next());
+            button.draw(&self.theme, self.text_scale);
+
+            if button.was_pressed().is_some() {
+                return Some(EndAction::Share);
+            }
         }
 
         let spacing = screen_width() / 5.0;
@@ -216,18 +987,51 @@ impl Screen {
             (x, y),
             (w, w),
             "Quit Game".to_string(),
-            Difficulty::Hard.as_color(),
-        );
-        button.draw();
+            Difficulty::Hard.as_color(&self.theme),
+        )
+        .with_focus(self.focus.next());
+        button.draw(&self.theme, self.text_scale);
 
         if button.was_pressed().is_some() {
-            std::process::exit(0);
+            return Some(EndAction::Quit);
         }
 
-        false
+        None
     }
 
-    pub fn draw_person(&mut self, num_wrong: usize) {
-        self.person.draw(num_wrong, &self.gallow);
+    pub fn draw_person(&mut self, visible: &[Stage], lost: bool) {
+        self.person.draw(visible, lost, &self.gallow, &self.theme);
     }
 }
+
+// the choice after `current`, going back to the first after the last
+fn next<T: IntoEnumIterator + PartialEq + Copy>(current: T) -> T {
+    let choices: Vec<T> = T::iter().collect();
+    let i = choices.iter().position(|c| *c == current).unwrap_or(0);
+    choices[(i + 1) % choices.len()]
+}
+
+// splits text between its words into lines no wider than `width`
+fn wrap_text(text: &str, text_size_ratio: f32, width: f32) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for word in text.split(' ') {
+        match lines.last_mut() {
+            Some(line)
+                if measure_text(
+                    &format!("{} {}", line, word),
+                    None,
+                    text_size_ratio as u16,
+                    1.0,
+                )
+                .width
+                    < width =>
+            {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,75 @@
+use macroquad::prelude::{
+    draw_text_ex, load_ttf_font_from_bytes, screen_height, screen_width, Color, Font,
+    TextDimensions, TextParams,
+};
+use std::sync::OnceLock;
+
+use crate::hangman::TEXT_SIZE;
+
+// the text sizes to pick from in the settings, on top of the size that goes
+// by the window
+pub const TEXT_SCALES: [f32; 4] = [1.0, 1.25, 1.5, 2.0];
+
+// macroquad's own font only has ascii, this one also has the accented,
+// cyrillic and greek letters of the language packs
+const FONT_BYTES: &[u8] = include_bytes!("../../../assets/fonts/DejaVuSans.ttf");
+
+static FONT: OnceLock<Font> = OnceLock::new();
+
+// has to be called once the window is open, before anything is drawn
+pub fn load_font() {
+    match load_ttf_font_from_bytes(FONT_BYTES) {
+        Ok(font) => {
+            let _ = FONT.set(font);
+        }
+        Err(err) => eprintln!("could not load font, using the default: {:?}", err),
+    }
+}
+
+fn font() -> Font {
+    FONT.get().copied().unwrap_or_default()
+}
+
+// the font size text is drawn at, it goes by the smaller side of the window
+// and is scaled by `scale`, the text size in the settings
+pub fn text_size_ratio(scale: f32) -> f32 {
+    let ratio = if screen_height() > screen_width() {
+        screen_width() / TEXT_SIZE
+    } else {
+        screen_height() / TEXT_SIZE
+    };
+
+    ratio * scale
+}
+
+// these stand in for macroquad's functions of the same name so that all text
+// is drawn in the game's font
+
+pub fn draw_text(text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+    draw_text_ex(
+        text,
+        x,
+        y,
+        TextParams {
+            font: font(),
+            font_size: font_size as u16,
+            color,
+            ..Default::default()
+        },
+    );
+}
+
+// `font` is only there to match macroquad, `None` is the game's font
+pub fn measure_text(
+    text: &str,
+    font: Option<Font>,
+    font_size: u16,
+    font_scale: f32,
+) -> TextDimensions {
+    macroquad::prelude::measure_text(
+        text,
+        Some(font.unwrap_or_else(self::font)),
+        font_size,
+        font_scale,
+    )
+}
@@ -1,168 +1,610 @@
+use hangman::cli::Options;
+use hangman::daily;
+use hangman::engine::{GameState, GameStatus, GuessOutcome};
+use hangman::figure;
+use hangman::pack::Pack;
+use hangman::rng::Rng;
+use hangman::save::{PickedBy, SavedGame};
+use hangman::settings::Settings;
+use hangman::stats::Stats;
+use hangman::words::{self, WordSource, WordSourceError, FULL_WORD_LIST};
 use macroquad::prelude::*;
-use rand::ChooseRandom;
-use std::{collections::HashSet, fs::File, io::BufRead, io::BufReader};
-use strum_macros::{EnumCount as EnumCountMacro, EnumIter};
+use std::path::PathBuf;
+
+pub use hangman::difficulty::Difficulty;
+pub use sound::Sounds;
 
 mod core;
-use crate::hangman::core::screen::{Screen, ScreenType};
+mod sound;
+mod theme;
+use crate::hangman::core::screen::{
+    EndAction, PauseAction, Screen, ScreenType, SecretWordAction, StartChoice,
+};
+use crate::hangman::sound::Effect;
+use crate::hangman::theme::Theme;
 
 pub const TEXT_SIZE: f32 = 20.0; // smaller the number, the bigger the text
-pub const TEXT_COLOR: Color = color_u8!(197, 194, 154, 255);
-pub const BACKGROUND_COLOR: Color = color_u8!(23, 23, 23, 255);
-pub const GALLOW_COLOR: Color = color_u8!(156, 119, 86, 255);
-pub const EASY_GREEN: Color = color_u8!(123, 119, 86, 255);
-pub const MEDIUM_YELLOW: Color = color_u8!(156, 119, 86, 255);
-pub const HARD_RED: Color = color_u8!(156, 85, 86, 255);
-pub const BUTTON_RED: Color = HARD_RED;
-pub const BUTTON_GRAY: Color = color_u8!(40, 37, 38, 255);
-pub const HANGMAN_COLOR: Color = color_u8!(111, 108, 90, 255);
 pub const TITLE_TEXT: [&str; 2] = ["Welcome to Hangman!", "Select your difficulty below."];
-pub const MAX_WRONG: usize = 9;
 
-#[derive(Debug, EnumCountMacro, EnumIter, Copy, Clone)]
-pub enum Difficulty {
-    Easy,   // length 3..=5
-    Medium, // length 6..=9
-    Hard,   // length 10+
+pub trait AsColor {
+    fn as_color(&self, theme: &Theme) -> Color;
 }
 
-impl Difficulty {
-    pub fn from_string(s: &str) -> Self {
-        match s {
-            "easy" => Self::Easy,
-            "medium" => Self::Medium,
-            "hard" => Self::Hard,
-            _ => unreachable!(),
-        }
-    }
-
-    pub fn as_str(&self) -> &str {
+impl AsColor for Difficulty {
+    fn as_color(&self, theme: &Theme) -> Color {
         match *self {
-            Self::Easy => "easy",
-            Self::Medium => "medium",
-            Self::Hard => "hard",
+            Self::Easy => theme.easy,
+            Self::Medium => theme.medium,
+            Self::Hard => theme.hard,
         }
     }
+}
 
-    pub fn as_color(&self) -> Color {
-        match *self {
-            Self::Easy => EASY_GREEN,
-            Self::Medium => MEDIUM_YELLOW,
-            Self::Hard => HARD_RED,
-        }
-    }
+// milliseconds since 1970, for the games and hints nobody asked to replay
+fn clock_seed() -> u64 {
+    (macroquad::miniquad::date::now() * 1000.0) as u64
 }
 
 #[derive(Debug)]
 pub struct Hangman {
-    letters_wrong: Vec<char>,
-    word: String,
-    guess: Vec<Option<char>>,
-    letters: HashSet<char>,
+    game: Option<GameState>,
+    difficulty: Difficulty,
+    // the day of the daily word, when that is the game being played
+    daily: Option<u64>,
+    // whether the daily result has been copied yet
+    shared: bool,
+    // the pack the word came from, shown as a hint while playing
+    category: Option<String>,
+    picked_by: PickedBy,
+    // seed for the next game, from the command line
+    seed: Option<u64>,
+    words: Box<dyn WordSource>,
+    packs: Vec<Pack>,
+    themes: Vec<Theme>,
+    stats: Stats,
+    stats_path: Option<PathBuf>,
+    save_path: Option<PathBuf>,
+    settings_path: Option<PathBuf>,
+    sounds: Sounds,
+    // what player one has typed so far in two player mode
+    secret_word: String,
+    secret_word_error: Option<String>,
+    check_dictionary: bool,
+    dictionary: Vec<String>,
     key_pressed: Option<char>,
     screen: Screen,
+    quitting: bool,
 }
 
 impl Hangman {
-    pub fn new() -> Hangman {
-        rand::srand(macroquad::miniquad::date::now() as _);
-        let letters = ('a'..='z').collect();
-
-        Hangman {
-            letters,
-            letters_wrong: vec![],
-            word: "".to_string(),
-            guess: vec![],
+    pub fn new(
+        words: Box<dyn WordSource>,
+        packs: Vec<Pack>,
+        sounds: Sounds,
+        options: &Options,
+    ) -> Hangman {
+        core::text::load_font();
+
+        let stats_path = Stats::default_path();
+        let stats = match stats_path.as_deref().map(Stats::load) {
+            Some(Ok(stats)) => stats,
+            Some(Err(err)) => {
+                eprintln!("could not load stats: {}", err);
+                Stats::default()
+            }
+            None => Stats::default(),
+        };
+
+        // a game left unfinished last time is kept so it can be continued
+        let save_path = SavedGame::default_path();
+        let saved = match save_path.as_deref().map(SavedGame::load) {
+            Some(Ok(saved)) => saved,
+            Some(Err(err)) => {
+                eprintln!("could not load saved game: {}", err);
+                None
+            }
+            None => None,
+        };
+        let (game, difficulty, daily, category, picked_by) = match saved {
+            Some(saved) => (
+                Some(saved.game),
+                saved.difficulty,
+                saved.daily,
+                saved.category,
+                saved.picked_by,
+            ),
+            None => (None, Difficulty::Easy, None, None, PickedBy::Game),
+        };
+
+        let settings_path = Settings::default_path();
+        let settings = match settings_path.as_deref().map(Settings::load) {
+            Some(Ok(settings)) => settings,
+            Some(Err(err)) => {
+                eprintln!("could not load settings: {}", err);
+                Settings::default()
+            }
+            None => Settings::default(),
+        };
+
+        // the bundled themes followed by any of the player's own
+        let mut themes = Theme::bundled();
+        match theme::default_dir().as_deref().map(theme::load_dir) {
+            Some(Ok(loaded)) => {
+                for theme in loaded {
+                    match theme {
+                        Ok(theme) => themes.push(theme),
+                        Err(err) => eprintln!("{}", err),
+                    }
+                }
+            }
+            Some(Err(err)) => eprintln!("{}", err),
+            None => {}
+        }
+
+        let mut hangman = Hangman {
+            game,
+            difficulty,
+            daily,
+            shared: false,
+            category,
+            picked_by,
+            seed: options.seed,
+            words,
+            packs,
+            themes,
+            stats,
+            stats_path,
+            save_path,
+            settings_path,
+            sounds,
+            secret_word: String::new(),
+            secret_word_error: None,
+            check_dictionary: true,
+            dictionary: words::parse_list(FULL_WORD_LIST),
             key_pressed: None,
             screen: Screen::new(),
+            quitting: false,
+        };
+        // the command line wins over the settings
+        hangman.screen.apply(&settings, &hangman.themes);
+        if let Some(mode) = options.mode {
+            hangman.screen.mode = mode;
+        }
+        if let Some(max_wrong) = options.max_wrong {
+            hangman.screen.max_wrong = max_wrong;
+        }
+        if let Some(name) = &options.theme {
+            match hangman.themes.iter().find(|theme| theme.name == *name) {
+                Some(theme) => hangman.screen.theme = theme.clone(),
+                None => eprintln!("unknown theme {}, using dark", name),
+            }
+        }
+        if let Some(layout) = options.layout {
+            hangman.screen.layout = layout;
+        }
+        let layout = hangman.screen.layout;
+        hangman.game = hangman.game.take().map(|game| game.with_layout(layout));
+        if let Some(name) = &options.category {
+            match hangman.packs.iter().position(|pack| pack.name == *name) {
+                Some(i) => hangman.screen.category = Some(i),
+                None => eprintln!("unknown category {}, using words", name),
+            }
         }
+
+        // a word or difficulty on the command line skips the start screen, and
+        // so does a difficulty to start with from the settings unless there
+        // is a game to continue
+        let can_continue = hangman.game.as_ref().is_some_and(|game| !game.is_over());
+        let difficulty = options
+            .difficulty
+            .or(settings.difficulty.filter(|_| !can_continue));
+        if let Some(word) = &options.word {
+            let difficulty = Difficulty::from_length(words::letter_count(word));
+            hangman.start(
+                GameState::new(word),
+                difficulty,
+                None,
+                None,
+                PickedBy::CommandLine,
+            );
+        } else if let Some(difficulty) = difficulty {
+            match hangman.new_game(difficulty) {
+                Ok(game) => {
+                    let category = hangman.category_name();
+                    hangman.start(game, difficulty, None, category, PickedBy::Game);
+                }
+                Err(err) => eprintln!("{}", err),
+            }
+        }
+
+        hangman
     }
 
     pub fn play(&mut self) {
         self.key_pressed = get_char_pressed();
+        self.screen.start_frame();
         match self.screen.screen_type {
             ScreenType::Start => {
-                let difficulty = self.screen.get_difficulty();
+                let can_continue = self.game.as_ref().is_some_and(|game| !game.is_over());
+                let category = self.category_name().unwrap_or_else(|| "words".to_string());
+                let max_wrong = self.screen.max_wrong;
+                let difficulty = self.screen.get_difficulty(can_continue, &category);
+                // the lives button here changes the same setting as the one
+                // on the settings screen, so it is saved straight away too
+                if self.screen.max_wrong != max_wrong {
+                    self.save_settings();
+                }
+
+                match difficulty {
+                    Some(StartChoice::Difficulty(difficulty)) => match self.new_game(difficulty) {
+                        Ok(game) => {
+                            let category = self.category_name();
+                            self.start(game, difficulty, None, category, PickedBy::Game);
+                        }
+                        Err(err) => eprintln!("{}", err),
+                    },
+                    Some(StartChoice::Daily) => {
+                        let day = daily::today(macroquad::miniquad::date::now());
+                        let game = daily::game(day);
+                        let difficulty = Difficulty::from_length(words::letter_count(game.word()));
+                        self.start(game, difficulty, Some(day), None, PickedBy::Game);
+                    }
+                    None => {}
+                }
+            }
+            ScreenType::SecretWord => {
+                if let Some(key) = self.key_pressed {
+                    if key.is_ascii_alphabetic() || words::PUNCTUATION.contains(&key) {
+                        self.secret_word.push(key.to_ascii_lowercase());
+                        self.secret_word_error = None;
+                    }
+                }
+                if is_key_pressed(KeyCode::Backspace) {
+                    self.secret_word.pop();
+                }
 
-                if let Some(difficulty) = difficulty {
-                    self.word = Self::get_word(difficulty);
-                    self.screen.screen_type = ScreenType::Main;
+                let mut action = self.screen.get_secret_word(
+                    &self.secret_word,
+                    self.secret_word_error.as_deref(),
+                    self.check_dictionary,
+                );
+                // enter submits unless it pressed the dictionary button
+                if action.is_none() && is_key_pressed(KeyCode::Enter) {
+                    action = Some(SecretWordAction::Submit);
+                }
+                if is_key_pressed(KeyCode::Escape) {
+                    self.secret_word.clear();
+                    self.secret_word_error = None;
+                    self.screen.screen_type = ScreenType::Start;
+                    return;
+                }
+
+                match action {
+                    Some(SecretWordAction::Submit) => {
+                        let dictionary = self.check_dictionary.then_some(&self.dictionary[..]);
+                        let word = self.secret_word.trim();
+                        match words::check_secret_word(word, dictionary) {
+                            Ok(()) => {
+                                let game = GameState::new(word);
+                                let difficulty = Difficulty::from_length(words::letter_count(word));
+                                self.secret_word.clear();
+                                self.start(game, difficulty, None, None, PickedBy::OtherPlayer);
+                            }
+                            Err(err) => self.secret_word_error = Some(err.to_string()),
+                        }
+                    }
+                    Some(SecretWordAction::ToggleDictionary) => {
+                        self.check_dictionary = !self.check_dictionary;
+                        self.secret_word_error = None;
+                    }
+                    None => {}
+                }
+            }
+            ScreenType::Statistics => {
+                if self.screen.draw_statistics(&self.stats) {
+                    self.screen.screen_type = ScreenType::Start;
+                }
+            }
+            ScreenType::Categories => self.screen.get_category(&self.packs),
+            ScreenType::Settings => {
+                self.screen.get_settings(&self.themes);
+                // an unfinished game picks up the new keyboard too
+                let layout = self.screen.layout;
+                self.game = self.game.take().map(|game| game.with_layout(layout));
+                if self.screen.screen_type != ScreenType::Settings {
+                    self.save_settings();
                 }
             }
             ScreenType::Main => {
-                clear_background(BACKGROUND_COLOR);
-                self.screen.draw_gallow();
-                self.screen.draw_person(self.letters_wrong.len());
-                self.screen.draw_word(&self.guess, self.word.clone());
-                let mut letter = self.screen.draw_keyboard(&self.letters);
+                if self.game.is_none() {
+                    self.screen.screen_type = ScreenType::Start;
+                    return;
+                }
+                self.draw_game();
+                if is_key_pressed(KeyCode::Escape) {
+                    self.screen.screen_type = ScreenType::Paused;
+                    return;
+                }
+
+                let Some(game) = self.game.as_mut() else {
+                    return;
+                };
+                let hint = self.screen.draw_hint(game.clue(), game.can_hint());
+                let mut letter = self.screen.draw_keyboard(
+                    game.alphabet().keyboard(),
+                    game.letters(),
+                    game.letters_wrong(),
+                );
 
                 if let Some(key) = self.key_pressed {
-                    letter = Some(key.to_ascii_lowercase());
+                    letter = Some(key);
                 }
 
-                if let Some(letter) = letter {
-                    if self.letters.contains(&letter) {
-                        self.letters.remove(&letter);
-                        if self.word.contains(letter) {
-                            self.guess.push(Some(letter));
-                        } else {
-                            self.letters_wrong.push(letter);
-                        }
+                let mut outcome = letter.map(|letter| game.guess(letter));
+                if hint {
+                    let rng = Rng::new(game.hint_seed().unwrap_or_else(clock_seed));
+                    if game.hint(&rng).is_some() {
+                        // a hint costs a wrong guess, so it is saved like one
+                        outcome = Some(GuessOutcome::Wrong);
                     }
                 }
-                if self.letters_wrong.len() == MAX_WRONG {
-                    self.screen.screen_type = ScreenType::End;
+                if self.screen.sound {
+                    let effect = match (game.status(), outcome) {
+                        (GameStatus::Won, _) => Some(Effect::Won),
+                        (GameStatus::Lost, _) => Some(Effect::Lost),
+                        (_, Some(GuessOutcome::Correct)) => Some(Effect::Correct),
+                        (_, Some(GuessOutcome::Wrong)) => Some(Effect::Wrong),
+                        _ => None,
+                    };
+                    if let Some(effect) = effect {
+                        self.sounds.play(effect);
+                    }
                 }
-                // use a set to check if the guess is complete
-                let guess_set: HashSet<Option<char>> = self.guess.iter().cloned().collect();
-                let word_set: HashSet<Option<char>> = self.word.chars().map(Some).collect();
-                if guess_set == word_set {
+                if game.is_over() {
+                    self.stats.record(
+                        game.word(),
+                        self.difficulty,
+                        game.status() == GameStatus::Won,
+                        game.wrong_count(),
+                        game.hints(),
+                    );
+                    self.save_stats();
+                    self.clear_saved_game();
                     self.screen.screen_type = ScreenType::End;
+                } else if matches!(outcome, Some(GuessOutcome::Correct | GuessOutcome::Wrong)) {
+                    self.save_game();
+                }
+            }
+            ScreenType::Paused => {
+                if self.game.is_none() {
+                    self.screen.screen_type = ScreenType::Start;
+                    return;
+                }
+                self.draw_game();
+
+                match self.screen.draw_pause() {
+                    Some(PauseAction::Resume) => self.screen.screen_type = ScreenType::Main,
+                    Some(PauseAction::Restart) => self.restart(),
+                    // the game is saved after every guess, so it can be
+                    // continued from the start screen
+                    Some(PauseAction::Menu) => self.screen.screen_type = ScreenType::Start,
+                    Some(PauseAction::Quit) => self.quitting = true,
+                    None => {}
                 }
             }
             ScreenType::End => {
-                self.screen.draw_gallow();
-                self.screen.draw_person(self.letters_wrong.len());
-                self.guess = self.word.chars().map(Some).collect();
-                self.screen.draw_word(&self.guess, self.word.clone());
-                let play_again = self
-                    .screen
-                    .draw_end_screen(self.letters_wrong.len() != MAX_WRONG);
-                if play_again {
-                    *self = Hangman::new();
+                let Some(game) = self.game.as_ref() else {
+                    self.screen.screen_type = ScreenType::Start;
+                    return;
+                };
+
+                let visible = figure::visible(game.wrong_count(), game.max_wrong());
+                self.screen.draw_gallow(&visible);
+                self.screen
+                    .draw_person(&visible, game.status() == GameStatus::Lost);
+                if let Some(category) = &self.category {
+                    self.screen.draw_category(category);
+                }
+                self.screen.draw_word(game.word());
+                let share = self
+                    .daily
+                    .map(|_| if self.shared { "Copied" } else { "Share" });
+                let mut action = self.screen.draw_end_screen(
+                    game.status() == GameStatus::Won,
+                    game.seed(),
+                    game.hints(),
+                    share,
+                );
+                if action.is_none() && is_key_pressed(KeyCode::Escape) {
+                    action = Some(EndAction::PlayAgain);
+                }
+                match action {
+                    Some(EndAction::PlayAgain) => {
+                        self.game = None;
+                        self.daily = None;
+                        self.shared = false;
+                        self.screen.play_again();
+                    }
+                    Some(EndAction::Quit) => self.quitting = true,
+                    Some(EndAction::Share) => {
+                        if let Some(day) = self.daily {
+                            let text = daily::share_text(day, game);
+                            // macroquad has no clipboard of its own, so this
+                            // goes through the miniquad context underneath it
+                            unsafe {
+                                get_internal_gl().quad_context.clipboard_set(&text);
+                            }
+                            self.shared = true;
+                        }
+                    }
+                    None => {}
                 }
             }
         }
     }
 
-    fn get_word(difficulty: Difficulty) -> String {
-        let path = format!("{}/assets/", env!("CARGO_MANIFEST_DIR"));
-
-        match difficulty {
-            Difficulty::Easy => {
-                let file = File::open(path + "easy-words.txt").unwrap();
-                let list: Vec<String> = BufReader::new(file)
-                    .lines()
-                    .collect::<Result<_, _>>()
-                    .unwrap();
-                list.choose().unwrap().clone()
-            }
-            Difficulty::Medium => {
-                let file = File::open(path + "medium-words.txt").unwrap();
-                let list: Vec<String> = BufReader::new(file)
-                    .lines()
-                    .collect::<Result<_, _>>()
-                    .unwrap();
-                list.choose().unwrap().clone()
-            }
-            Difficulty::Hard => {
-                let file = File::open(path + "hard-words.txt").unwrap();
-                let list: Vec<String> = BufReader::new(file)
-                    .lines()
-                    .collect::<Result<_, _>>()
-                    .unwrap();
-                list.choose().unwrap().clone()
+    fn start(
+        &mut self,
+        game: GameState,
+        difficulty: Difficulty,
+        daily: Option<u64>,
+        category: Option<String>,
+        picked_by: PickedBy,
+    ) {
+        self.game = Some(
+            game.with_max_wrong(self.screen.max_wrong)
+                .with_layout(self.screen.layout),
+        );
+        self.difficulty = difficulty;
+        self.daily = daily;
+        self.category = category;
+        self.picked_by = picked_by;
+        self.shared = false;
+        // replaces any game that was left unfinished
+        self.save_game();
+        self.screen.screen_type = ScreenType::Main;
+    }
+
+    // starts another game like the one being left, which isn't counted: the
+    // same daily word, a new word from player one, the word from the command
+    // line again, or otherwise a new word of the same difficulty and category
+    fn restart(&mut self) {
+        let Some(game) = &self.game else {
+            return;
+        };
+        let word = game.word().to_string();
+
+        self.screen.play_again();
+        if let Some(day) = self.daily {
+            self.start(
+                daily::game(day),
+                self.difficulty,
+                Some(day),
+                None,
+                PickedBy::Game,
+            );
+            return;
+        }
+        match self.picked_by {
+            PickedBy::Game => match self.new_game(self.difficulty) {
+                Ok(game) => {
+                    let category = self.category_name();
+                    self.start(game, self.difficulty, None, category, PickedBy::Game);
+                }
+                Err(err) => eprintln!("{}", err),
+            },
+            PickedBy::OtherPlayer => {
+                self.game = None;
+                self.clear_saved_game();
+                self.secret_word.clear();
+                self.secret_word_error = None;
+                self.screen.screen_type = ScreenType::SecretWord;
+            }
+            PickedBy::CommandLine => {
+                let game = GameState::new(&word);
+                self.start(game, self.difficulty, None, None, PickedBy::CommandLine);
+            }
+        }
+    }
+
+    // whether a quit button was pressed, the window is closed once
+    // everything has been saved with `quit`
+    pub fn is_quitting(&self) -> bool {
+        self.quitting
+    }
+
+    // called when the game is about to close, from a quit button or the
+    // window being closed
+    pub fn quit(&self) {
+        self.save_game();
+        self.save_stats();
+        // settings are otherwise saved on leaving their screen
+        if self.screen.screen_type == ScreenType::Settings {
+            self.save_settings();
+        }
+    }
+
+    // the gallow, person and word of the game being played, under the
+    // keyboard or the pause menu
+    fn draw_game(&mut self) {
+        let Some(game) = &self.game else {
+            return;
+        };
+
+        clear_background(self.screen.theme.background);
+        let visible = figure::visible(game.wrong_count(), game.max_wrong());
+        self.screen.draw_gallow(&visible);
+        self.screen
+            .draw_person(&visible, game.status() == GameStatus::Lost);
+        if let Some(category) = &self.category {
+            self.screen.draw_category(category);
+        }
+        self.screen.draw_word(&game.masked_word());
+    }
+
+    fn save_game(&self) {
+        let (Some(path), Some(game)) = (&self.save_path, &self.game) else {
+            return;
+        };
+        if game.is_over() {
+            return;
+        }
+
+        let saved = SavedGame {
+            game: game.clone(),
+            difficulty: self.difficulty,
+            daily: self.daily,
+            category: self.category.clone(),
+            picked_by: self.picked_by,
+        };
+        if let Err(err) = saved.save(path) {
+            eprintln!("could not save game: {}", err);
+        }
+    }
+
+    fn clear_saved_game(&self) {
+        if let Some(path) = &self.save_path {
+            if let Err(err) = SavedGame::clear(path) {
+                eprintln!("could not remove saved game: {}", err);
+            }
+        }
+    }
+
+    fn save_settings(&self) {
+        if let Some(path) = &self.settings_path {
+            if let Err(err) = self.screen.settings().save(path) {
+                eprintln!("could not save settings: {}", err);
             }
         }
     }
+
+    fn save_stats(&self) {
+        if let Some(path) = &self.stats_path {
+            if let Err(err) = self.stats.save(path) {
+                eprintln!("could not save stats: {}", err);
+            }
+        }
+    }
+
+    // the name of the pack new games pick from
+    fn category_name(&self) -> Option<String> {
+        self.screen.category.map(|i| self.packs[i].name.clone())
+    }
+
+    // every game gets its own seed so it can be played again with --seed
+    fn new_game(&mut self, difficulty: Difficulty) -> Result<GameState, WordSourceError> {
+        let seed = self.seed.take().unwrap_or_else(clock_seed);
+        // packs in other languages are played with their own alphabet
+        let alphabet = self
+            .screen
+            .category
+            .and_then(|i| self.packs[i].alphabet().cloned())
+            .unwrap_or_default();
+        let words: &mut dyn WordSource = match self.screen.category {
+            Some(i) => &mut self.packs[i],
+            None => self.words.as_mut(),
+        };
+
+        words::new_game(words, difficulty, self.screen.mode, alphabet, seed)
+    }
 }
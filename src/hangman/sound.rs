@@ -0,0 +1,57 @@
+use macroquad::audio::{load_sound_from_bytes, play_sound_once, Sound};
+
+// short tones made for the game, compiled into the binary
+const CORRECT: &[u8] = include_bytes!("../../assets/sounds/correct.wav");
+const WRONG: &[u8] = include_bytes!("../../assets/sounds/wrong.wav");
+const WON: &[u8] = include_bytes!("../../assets/sounds/won.wav");
+const LOST: &[u8] = include_bytes!("../../assets/sounds/lost.wav");
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Effect {
+    Correct,
+    Wrong,
+    Won,
+    Lost,
+}
+
+/// The sound effects, any that couldn't be loaded are left silent.
+#[derive(Debug, Default)]
+pub struct Sounds {
+    correct: Option<Sound>,
+    wrong: Option<Sound>,
+    won: Option<Sound>,
+    lost: Option<Sound>,
+}
+
+impl Sounds {
+    pub async fn load() -> Self {
+        Self {
+            correct: load(CORRECT).await,
+            wrong: load(WRONG).await,
+            won: load(WON).await,
+            lost: load(LOST).await,
+        }
+    }
+
+    pub fn play(&self, effect: Effect) {
+        let sound = match effect {
+            Effect::Correct => self.correct,
+            Effect::Wrong => self.wrong,
+            Effect::Won => self.won,
+            Effect::Lost => self.lost,
+        };
+        if let Some(sound) = sound {
+            play_sound_once(sound);
+        }
+    }
+}
+
+async fn load(bytes: &[u8]) -> Option<Sound> {
+    match load_sound_from_bytes(bytes).await {
+        Ok(sound) => Some(sound),
+        Err(err) => {
+            eprintln!("could not load sound: {}", err);
+            None
+        }
+    }
+}
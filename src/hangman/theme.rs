@@ -0,0 +1,166 @@
+use hangman::config;
+use macroquad::prelude::Color;
+use serde::Deserialize;
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+// the themes that come with the game, compiled into the binary
+pub const DARK: &str = include_str!("../../assets/themes/dark.toml");
+pub const LIGHT: &str = include_str!("../../assets/themes/light.toml");
+pub const HIGH_CONTRAST: &str = include_str!("../../assets/themes/high-contrast.toml");
+pub const SOLARIZED: &str = include_str!("../../assets/themes/solarized.toml");
+pub const COLORBLIND: &str = include_str!("../../assets/themes/colorblind.toml");
+
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(PathBuf, io::Error),
+    Toml(String, toml::de::Error),
+    // a color that isn't #rrggbb or #rrggbbaa, with the theme and key it was in
+    Color(String, String, String),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            Self::Toml(name, err) => write!(f, "invalid theme {}: {}", name, err),
+            Self::Color(name, key, value) => {
+                write!(f, "theme {} has an invalid {} color: {}", name, key, value)
+            }
+        }
+    }
+}
+
+impl Error for ThemeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err),
+            Self::Toml(_, err) => Some(err),
+            Self::Color(_, _, _) => None,
+        }
+    }
+}
+
+// a theme file, every color written as #rrggbb or #rrggbbaa
+#[derive(Debug, Deserialize)]
+struct ThemeFile {
+    name: String,
+    text: String,
+    background: String,
+    gallow: String,
+    person: String,
+    eyes: String,
+    dead: String,
+    button: String,
+    selected: String,
+    easy: String,
+    medium: String,
+    hard: String,
+}
+
+/// The colors everything on screen is drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub text: Color,
+    pub background: Color,
+    pub gallow: Color,
+    pub person: Color,
+    pub eyes: Color,
+    // the eyes and mouth once the person has been hanging for a while
+    pub dead: Color,
+    pub button: Color,
+    // buttons for what is picked or can be pressed, such as guessed letters
+    pub selected: Color,
+    pub easy: Color,
+    pub medium: Color,
+    pub hard: Color,
+}
+
+impl Theme {
+    fn from_file(file: ThemeFile) -> Result<Self, ThemeError> {
+        let color = |key: &str, value: &str| {
+            parse_color(value)
+                .ok_or_else(|| ThemeError::Color(file.name.clone(), key.to_string(), value.into()))
+        };
+
+        Ok(Self {
+            text: color("text", &file.text)?,
+            background: color("background", &file.background)?,
+            gallow: color("gallow", &file.gallow)?,
+            person: color("person", &file.person)?,
+            eyes: color("eyes", &file.eyes)?,
+            dead: color("dead", &file.dead)?,
+            button: color("button", &file.button)?,
+            selected: color("selected", &file.selected)?,
+            easy: color("easy", &file.easy)?,
+            medium: color("medium", &file.medium)?,
+            hard: color("hard", &file.hard)?,
+            name: file.name,
+        })
+    }
+
+    // `source` names the theme in errors, usually the file it came from
+    pub fn parse(source: &str, text: &str) -> Result<Self, ThemeError> {
+        let file = toml::from_str(text).map_err(|err| ThemeError::Toml(source.to_string(), err))?;
+        Self::from_file(file)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ThemeError> {
+        let text =
+            fs::read_to_string(path).map_err(|err| ThemeError::Io(path.to_path_buf(), err))?;
+        Self::parse(&path.display().to_string(), &text)
+    }
+
+    // every theme that comes with the game, dark first
+    pub fn bundled() -> Vec<Self> {
+        [
+            ("dark.toml", DARK),
+            ("light.toml", LIGHT),
+            ("high-contrast.toml", HIGH_CONTRAST),
+            ("solarized.toml", SOLARIZED),
+            ("colorblind.toml", COLORBLIND),
+        ]
+        .iter()
+        // these are checked by hand before they are shipped
+        .map(|(source, text)| Self::parse(source, text).expect("bundled themes are valid"))
+        .collect()
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::parse("dark.toml", DARK).expect("bundled themes are valid")
+    }
+}
+
+// "#rrggbb" or "#rrggbbaa"
+fn parse_color(text: &str) -> Option<Color> {
+    let hex = text.strip_prefix('#')?;
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut channels = [255u8; 4];
+    for (i, channel) in channels.iter_mut().enumerate().take(hex.len() / 2) {
+        *channel = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    let [r, g, b, a] = channels;
+
+    Some(Color::from_rgba(r, g, b, a))
+}
+
+// where themes of your own go, `None` when the platform has no config dir
+pub fn default_dir() -> Option<PathBuf> {
+    config::path("themes")
+}
+
+// every .toml theme in the directory, sorted by file name. A missing
+// directory just has no themes
+pub fn load_dir(dir: &Path) -> Result<Vec<Result<Theme, ThemeError>>, ThemeError> {
+    config::load_dir(dir, &["toml"], Theme::load)
+        .map_err(|err| ThemeError::Io(dir.to_path_buf(), err))
+}
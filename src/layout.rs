@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{EnumIter, EnumString};
+
+/// Where the letters sit on the on screen keyboard for the english alphabet,
+/// so they can be clicked where they are on a physical keyboard.
+#[derive(
+    Debug, Default, EnumIter, EnumString, Copy, Clone, Eq, PartialEq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Layout {
+    #[default]
+    Qwerty,
+    Azerty,
+    Qwertz,
+    Dvorak,
+    Colemak,
+    Alphabetical,
+}
+
+// the letter keys of each layout, top row first, punctuation keys left out
+const QWERTY: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+const AZERTY: [&str; 3] = ["azertyuiop", "qsdfghjklm", "wxcvbn"];
+const QWERTZ: [&str; 3] = ["qwertzuiop", "asdfghjkl", "yxcvbnm"];
+const DVORAK: [&str; 3] = ["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"];
+const COLEMAK: [&str; 3] = ["qwfpgjluy", "arstdhneio", "zxcvbkm"];
+const ALPHABETICAL: [&str; 3] = ["abcdefghi", "jklmnopqr", "stuvwxyz"];
+
+impl Layout {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Self::Qwerty => "qwerty",
+            Self::Azerty => "azerty",
+            Self::Qwertz => "qwertz",
+            Self::Dvorak => "dvorak",
+            Self::Colemak => "colemak",
+            Self::Alphabetical => "alphabetical",
+        }
+    }
+
+    pub fn rows(&self) -> &'static [&'static str] {
+        match *self {
+            Self::Qwerty => &QWERTY,
+            Self::Azerty => &AZERTY,
+            Self::Qwertz => &QWERTZ,
+            Self::Dvorak => &DVORAK,
+            Self::Colemak => &COLEMAK,
+            Self::Alphabetical => &ALPHABETICAL,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Headless hangman logic that the GUI and any other frontend are built on.
+
+pub mod alphabet;
+pub mod cli;
+pub mod config;
+pub mod daily;
+pub mod difficulty;
+pub mod engine;
+pub mod figure;
+pub mod layout;
+pub mod pack;
+pub mod rng;
+pub mod save;
+pub mod scoring;
+pub mod settings;
+pub mod solver;
+pub mod stats;
+pub mod words;
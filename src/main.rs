@@ -1,14 +1,59 @@
-use macroquad::prelude::next_frame;
+use macroquad::prelude::{is_quit_requested, next_frame, prevent_quit, Conf};
+use std::process;
 
 mod hangman;
-use hangman::Hangman;
+use crate::hangman::{Hangman, Sounds};
+use ::hangman::cli::{CliError, Options};
 
-#[macroquad::main("Hangman")]
+fn options() -> Options {
+    match Options::from_env() {
+        Ok(options) => options,
+        Err(CliError::Help) => {
+            println!("{}", CliError::Help);
+            process::exit(0);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    }
+}
+
+fn window_conf() -> Conf {
+    Conf {
+        window_title: "Hangman".to_string(),
+        fullscreen: options().fullscreen,
+        ..Default::default()
+    }
+}
+
+#[macroquad::main(window_conf)]
 async fn main() {
-    let mut hangman = Hangman::new();
+    let options = options();
+    let words = match options.word_source() {
+        Ok(words) => words,
+        Err(err) => {
+            eprintln!("could not read word file: {}", err);
+            process::exit(1);
+        }
+    };
+    let (packs, errors) = options.packs();
+    for err in errors {
+        eprintln!("{}", err);
+    }
+    let sounds = Sounds::load().await;
+    let mut hangman = Hangman::new(words, packs, sounds, &options);
+
+    // closing the window and the quit buttons are handled here so everything
+    // can be saved first
+    prevent_quit();
 
     loop {
         hangman.play();
+        if is_quit_requested() || hangman.is_quitting() {
+            hangman.quit();
+            break;
+        }
         next_frame().await;
     }
 }
@@ -0,0 +1,283 @@
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::alphabet::{Alphabet, ENGLISH_LETTERS};
+use crate::config;
+use crate::difficulty::Difficulty;
+use crate::layout::Layout;
+use crate::words::{self, WordSource, WordSourceError, PHRASES};
+
+// the packs that come with the game, compiled into the binary
+pub const ANIMALS: &str = include_str!("../assets/packs/animals.toml");
+pub const COUNTRIES: &str = include_str!("../assets/packs/countries.toml");
+pub const PROGRAMMING: &str = include_str!("../assets/packs/programming.toml");
+pub const GERMAN: &str = include_str!("../assets/packs/german.toml");
+pub const SPANISH: &str = include_str!("../assets/packs/spanish.toml");
+pub const FRENCH: &str = include_str!("../assets/packs/french.toml");
+pub const RUSSIAN: &str = include_str!("../assets/packs/russian.toml");
+pub const GREEK: &str = include_str!("../assets/packs/greek.toml");
+
+#[derive(Debug)]
+pub enum PackError {
+    Io(PathBuf, io::Error),
+    Toml(String, toml::de::Error),
+    Json(String, serde_json::Error),
+    // the file was neither .toml nor .json
+    Format(PathBuf),
+    Empty(String),
+    // an equivalent that wasn't a single letter, with the pack it was in
+    Equivalent(String, String),
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            Self::Toml(name, err) => write!(f, "invalid pack {}: {}", name, err),
+            Self::Json(name, err) => write!(f, "invalid pack {}: {}", name, err),
+            Self::Format(path) => write!(f, "{} is not a .toml or .json pack", path.display()),
+            Self::Empty(name) => write!(f, "pack {} has no words", name),
+            Self::Equivalent(name, letter) => {
+                write!(
+                    f,
+                    "pack {} has an equivalent that isn't one letter: {}",
+                    name, letter
+                )
+            }
+        }
+    }
+}
+
+impl Error for PackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err),
+            Self::Toml(_, err) => Some(err),
+            Self::Json(_, err) => Some(err),
+            Self::Format(_) | Self::Empty(_) | Self::Equivalent(_, _) => None,
+        }
+    }
+}
+
+// a word in a pack file, either just the word or a table that can also tag
+// it with a difficulty and give it a clue
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PackWord {
+    Plain(String),
+    Tagged {
+        word: String,
+        difficulty: Option<Difficulty>,
+        clue: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct PackFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    words: Vec<PackWord>,
+    // a pack in another language can bring its own alphabet, keyboard and
+    // letters that count as others, any left out are the english ones
+    alphabet: Option<String>,
+    keyboard: Option<Vec<String>>,
+    equivalents: Option<BTreeMap<String, String>>,
+}
+
+/// A themed category of words, such as animals or countries. Words without a
+/// difficulty tag go by their length like any other word list.
+#[derive(Debug)]
+pub struct Pack {
+    pub name: String,
+    pub description: String,
+    lists: HashMap<Difficulty, Vec<String>>,
+    // every word in the pack, for difficulties nothing was tagged with
+    all: Vec<String>,
+    clues: HashMap<String, String>,
+    alphabet: Option<Alphabet>,
+}
+
+impl Pack {
+    pub fn new<I, S>(name: &str, description: &str, words: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Option<Difficulty>)>,
+        S: Into<String>,
+    {
+        let mut pack = Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            lists: HashMap::new(),
+            all: vec![],
+            clues: HashMap::new(),
+            alphabet: None,
+        };
+        for (word, difficulty) in words {
+            let word = word.into().trim().to_lowercase();
+            if word.is_empty() {
+                continue;
+            }
+
+            let difficulty =
+                difficulty.unwrap_or_else(|| Difficulty::from_length(words::letter_count(&word)));
+            pack.lists.entry(difficulty).or_default().push(word.clone());
+            pack.all.push(word);
+        }
+
+        pack
+    }
+
+    fn from_file(file: PackFile) -> Result<Self, PackError> {
+        let alphabet =
+            if file.alphabet.is_some() || file.keyboard.is_some() || file.equivalents.is_some() {
+                let mut equivalents = vec![];
+                for (from, to) in file.equivalents.unwrap_or_default() {
+                    let (Some(from_letter), Some(to_letter)) = (single(&from), single(&to)) else {
+                        return Err(PackError::Equivalent(
+                            file.name,
+                            format!("{} = {}", from, to),
+                        ));
+                    };
+                    equivalents.push((from_letter, to_letter));
+                }
+                let keyboard: Vec<&str> = match &file.keyboard {
+                    Some(rows) => rows.iter().map(String::as_str).collect(),
+                    None => Layout::Qwerty.rows().to_vec(),
+                };
+                let letters = file.alphabet.as_deref().unwrap_or(ENGLISH_LETTERS);
+
+                Some(Alphabet::new(letters, &keyboard, equivalents))
+            } else {
+                None
+            };
+
+        let mut clues = HashMap::new();
+        let words: Vec<(String, Option<Difficulty>)> = file
+            .words
+            .into_iter()
+            .map(|word| match word {
+                PackWord::Plain(word) => (word, None),
+                PackWord::Tagged {
+                    word,
+                    difficulty,
+                    clue,
+                } => {
+                    if let Some(clue) = clue {
+                        clues.insert(word.trim().to_lowercase(), clue);
+                    }
+                    (word, difficulty)
+                }
+            })
+            .collect();
+        let mut pack = Self::new(&file.name, &file.description, words);
+        pack.clues = clues;
+        pack.alphabet = alphabet;
+
+        if pack.all.is_empty() {
+            Err(PackError::Empty(pack.name))
+        } else {
+            Ok(pack)
+        }
+    }
+
+    // `source` names the pack in errors, usually the file it came from
+    pub fn parse_toml(source: &str, text: &str) -> Result<Self, PackError> {
+        let file = toml::from_str(text).map_err(|err| PackError::Toml(source.to_string(), err))?;
+        Self::from_file(file)
+    }
+
+    pub fn parse_json(source: &str, text: &str) -> Result<Self, PackError> {
+        let file =
+            serde_json::from_str(text).map_err(|err| PackError::Json(source.to_string(), err))?;
+        Self::from_file(file)
+    }
+
+    // reads a .toml or .json pack
+    pub fn load(path: &Path) -> Result<Self, PackError> {
+        let source = path.display().to_string();
+        let parse = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::parse_toml,
+            Some("json") => Self::parse_json,
+            _ => return Err(PackError::Format(path.to_path_buf())),
+        };
+        let text =
+            fs::read_to_string(path).map_err(|err| PackError::Io(path.to_path_buf(), err))?;
+
+        parse(&source, &text)
+    }
+
+    // the alphabet the pack's words are played with, `None` for english
+    pub fn alphabet(&self) -> Option<&Alphabet> {
+        self.alphabet.as_ref()
+    }
+
+    // the phrases, one per line, as a pack of their own
+    pub fn phrases(text: &str) -> Self {
+        Self::new(
+            "phrases",
+            "sayings and phrases, spaces and punctuation are free",
+            words::parse_list(text)
+                .into_iter()
+                .map(|phrase| (phrase, None)),
+        )
+    }
+
+    // every pack that comes with the game
+    pub fn bundled() -> Vec<Self> {
+        let mut packs = vec![Self::phrases(PHRASES)];
+        for (source, text) in [
+            ("animals.toml", ANIMALS),
+            ("countries.toml", COUNTRIES),
+            ("programming.toml", PROGRAMMING),
+            ("german.toml", GERMAN),
+            ("spanish.toml", SPANISH),
+            ("french.toml", FRENCH),
+            ("russian.toml", RUSSIAN),
+            ("greek.toml", GREEK),
+        ] {
+            // these are checked by hand before they are shipped
+            packs.push(Self::parse_toml(source, text).expect("bundled packs are valid"));
+        }
+
+        packs
+    }
+}
+
+impl WordSource for Pack {
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError> {
+        match self.lists.get(&difficulty) {
+            Some(words) => Ok(words),
+            None => Ok(&self.all),
+        }
+    }
+
+    fn clue(&self, word: &str) -> Option<&str> {
+        self.clues.get(word).map(String::as_str)
+    }
+}
+
+// the only character in `text`
+fn single(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// where packs of your own go, `None` when the platform has no config dir
+pub fn default_dir() -> Option<PathBuf> {
+    config::path("packs")
+}
+
+// every .toml and .json pack in the directory, sorted by file name. A missing
+// directory just has no packs
+pub fn load_dir(dir: &Path) -> Result<Vec<Result<Pack, PackError>>, PackError> {
+    config::load_dir(dir, &["toml", "json"], Pack::load)
+        .map_err(|err| PackError::Io(dir.to_path_buf(), err))
+}
@@ -0,0 +1,36 @@
+use quad_rand::RandGenerator;
+use std::{fmt, ops::Deref};
+
+/// A random number generator that remembers the seed it started from, so the
+/// same seed always picks the same word.
+pub struct Rng {
+    seed: u64,
+    generator: RandGenerator,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let generator = RandGenerator::new();
+        generator.srand(seed);
+
+        Self { seed, generator }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Deref for Rng {
+    type Target = RandGenerator;
+
+    fn deref(&self) -> &RandGenerator {
+        &self.generator
+    }
+}
+
+impl fmt::Debug for Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rng").field("seed", &self.seed).finish()
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::config;
+use crate::difficulty::Difficulty;
+use crate::engine::GameState;
+
+/// Who picked the word of a game, so a restart can pick the next one the
+/// same way. Daily games are told apart by their day instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PickedBy {
+    // from the word lists or a pack
+    #[default]
+    Game,
+    // typed in by player one in two player mode
+    OtherPlayer,
+    // given with --word
+    CommandLine,
+}
+
+/// A game that was left before it finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub game: GameState,
+    pub difficulty: Difficulty,
+    // the day of the daily word, for daily games
+    #[serde(default)]
+    pub daily: Option<u64>,
+    // the name of the pack the word came from
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub picked_by: PickedBy,
+}
+
+impl SavedGame {
+    // where the save lives, `None` when the platform has no config dir
+    pub fn default_path() -> Option<PathBuf> {
+        config::path("save.json")
+    }
+
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        match config::read(path)? {
+            Some(text) => Ok(Some(serde_json::from_str(&text)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        config::write(path, &serde_json::to_string(self)?)
+    }
+
+    // removes the save once its game is over
+    pub fn clear(path: &Path) -> io::Result<()> {
+        match fs::remove_file(path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::difficulty::Difficulty;
+use crate::engine::{GameState, MAX_WRONG};
+use crate::solver::{self, FrequencySolver};
+use crate::words::{WordSource, WordSourceError};
+
+/// How hard a word is to guess, higher is harder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordScore {
+    pub word: String,
+    // 0..=1, how rarely the word's letters show up in the dictionary
+    pub rarity: f64,
+    pub unique_letters: usize,
+    // wrong guesses the frequency solver needed to find the word
+    pub solver_wrong: usize,
+    pub score: f64,
+}
+
+// the fraction of dictionary words each letter appears in
+fn letter_frequencies(dictionary: &[String]) -> HashMap<char, f64> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for word in dictionary {
+        let mut seen = vec![];
+        for c in word.chars() {
+            if !seen.contains(&c) {
+                seen.push(c);
+                *counts.entry(c).or_default() += 1;
+            }
+        }
+    }
+
+    let total = dictionary.len().max(1) as f64;
+    counts
+        .into_iter()
+        .map(|(c, count)| (c, count as f64 / total))
+        .collect()
+}
+
+fn score_with(word: &str, frequencies: &HashMap<char, f64>, dictionary: &[String]) -> WordScore {
+    let mut letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+    letters.sort_unstable();
+    letters.dedup();
+
+    let rarity = letters
+        .iter()
+        .map(|c| 1.0 - frequencies.get(c).copied().unwrap_or(0.0))
+        .sum::<f64>()
+        / letters.len().max(1) as f64;
+
+    let mut game = GameState::new(word);
+    solver::solve(&mut game, &mut FrequencySolver, dictionary);
+    let solver_wrong = game.wrong_count();
+
+    // each part is between 0 and 1 so they carry roughly equal weight
+    let score = rarity + 1.0 / letters.len().max(1) as f64 + solver_wrong as f64 / MAX_WRONG as f64;
+
+    WordScore {
+        word: word.to_string(),
+        rarity,
+        unique_letters: letters.len(),
+        solver_wrong,
+        score,
+    }
+}
+
+pub fn score_word(word: &str, dictionary: &[String]) -> WordScore {
+    score_with(word, &letter_frequencies(dictionary), dictionary)
+}
+
+// scores every word in the list, easiest first
+pub fn score_words(words: &[String], dictionary: &[String]) -> Vec<WordScore> {
+    let frequencies = letter_frequencies(dictionary);
+    let mut scores: Vec<WordScore> = words
+        .iter()
+        .map(|word| score_with(word, &frequencies, dictionary))
+        .collect();
+    scores.sort_by(|a, b| a.score.total_cmp(&b.score));
+
+    scores
+}
+
+/// Sorts a list of words into difficulties by their score instead of their
+/// length, the easiest third of the words are easy and so on. Scoring plays a
+/// game for every word, which takes a moment for a full dictionary, so it is
+/// all done up front in `new` rather than when the first word is picked.
+#[derive(Debug)]
+pub struct ScoredWords {
+    lists: HashMap<Difficulty, Vec<String>>,
+}
+
+impl ScoredWords {
+    pub fn new(words: Vec<String>) -> Self {
+        let scores = score_words(&words, &words);
+        let third = scores.len().div_ceil(3);
+
+        let mut lists: HashMap<Difficulty, Vec<String>> = HashMap::new();
+        for (i, chunk) in scores.chunks(third.max(1)).enumerate() {
+            let difficulty = match i {
+                0 => Difficulty::Easy,
+                1 => Difficulty::Medium,
+                _ => Difficulty::Hard,
+            };
+            lists
+                .entry(difficulty)
+                .or_default()
+                .extend(chunk.iter().map(|s| s.word.clone()));
+        }
+
+        Self { lists }
+    }
+}
+
+impl WordSource for ScoredWords {
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError> {
+        Ok(self.lists.get(&difficulty).map_or(&[], Vec::as_slice))
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::config;
+use crate::difficulty::Difficulty;
+use crate::engine::MAX_WRONG;
+use crate::layout::Layout;
+
+/// What was picked on the settings screen, kept for the next launch. Any
+/// setting missing from the file is left at its default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // the name of the color theme
+    pub theme: String,
+    pub layout: Layout,
+    pub max_wrong: usize,
+    pub sound: bool,
+    pub text_scale: f32,
+    pub cues: bool,
+    // a game of this difficulty starts straight away at launch, `None` shows
+    // the start screen
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "dark".to_string(),
+            layout: Layout::default(),
+            max_wrong: MAX_WRONG,
+            sound: true,
+            text_scale: 1.0,
+            cues: false,
+            difficulty: None,
+        }
+    }
+}
+
+impl Settings {
+    // where the settings live, `None` when the platform has no config dir
+    pub fn default_path() -> Option<PathBuf> {
+        config::path("settings.toml")
+    }
+
+    // a missing file is treated as nothing having been changed yet
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match config::read(path)? {
+            Some(text) => {
+                toml::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text =
+            toml::to_string(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        config::write(path, &text)
+    }
+}
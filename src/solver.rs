@@ -0,0 +1,231 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::engine::{GameState, GameStatus};
+
+// english letters from most to least common, used when no word fits
+const LETTER_FREQUENCY: &str = "etaoinshrdlcumwfgypbvkjxqz";
+
+/// Picks the next letter to guess in a game of hangman.
+pub trait Solver {
+    fn name(&self) -> &str;
+
+    // `pattern` is the masked word with '_' for letters that are still hidden
+    fn next_guess(&mut self, pattern: &str, wrong: &[char], dictionary: &[&str]) -> Option<char>;
+}
+
+// the words in the dictionary that could still be the answer
+pub fn candidates<'a>(pattern: &str, wrong: &[char], dictionary: &[&'a str]) -> Vec<&'a str> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let revealed: HashSet<char> = pattern.iter().copied().filter(|c| *c != '_').collect();
+
+    dictionary
+        .iter()
+        .copied()
+        .filter(|word| word.chars().count() == pattern.len())
+        .filter(|word| {
+            word.chars().zip(&pattern).all(|(c, p)| match *p {
+                '_' => !revealed.contains(&c) && !wrong.contains(&c),
+                p => c == p,
+            })
+        })
+        .collect()
+}
+
+// every letter that hasn't been tried yet, most common first
+fn untried(pattern: &str, wrong: &[char]) -> Vec<char> {
+    LETTER_FREQUENCY
+        .chars()
+        .filter(|c| !pattern.contains(*c) && !wrong.contains(c))
+        .collect()
+}
+
+/// Guesses the letter that appears in the most remaining candidates.
+#[derive(Debug, Default)]
+pub struct FrequencySolver;
+
+impl Solver for FrequencySolver {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn next_guess(&mut self, pattern: &str, wrong: &[char], dictionary: &[&str]) -> Option<char> {
+        let words = candidates(pattern, wrong, dictionary);
+        let letters = untried(pattern, wrong);
+
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for word in &words {
+            let mut seen = vec![];
+            for c in word.chars() {
+                if !seen.contains(&c) {
+                    seen.push(c);
+                    *counts.entry(c).or_default() += 1;
+                }
+            }
+        }
+
+        // max_by_key keeps the last maximum, so walk the letters rarest first
+        // to fall back on the more common letter when counts are tied
+        letters
+            .iter()
+            .rev()
+            .copied()
+            .max_by_key(|c| counts.get(c).copied().unwrap_or(0))
+    }
+}
+
+/// Guesses the letter whose answer splits the remaining candidates the most
+/// evenly, which tells us the most about the word whatever happens.
+#[derive(Debug, Default)]
+pub struct EntropySolver;
+
+impl Solver for EntropySolver {
+    fn name(&self) -> &str {
+        "entropy"
+    }
+
+    fn next_guess(&mut self, pattern: &str, wrong: &[char], dictionary: &[&str]) -> Option<char> {
+        let words = candidates(pattern, wrong, dictionary);
+        let letters = untried(pattern, wrong);
+        let total = words.len() as f64;
+
+        let entropy = |letter: char| {
+            // a BTreeMap so the sum below always adds up in the same order
+            let mut families: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+            for word in &words {
+                let positions = word
+                    .chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == letter)
+                    .map(|(i, _)| i)
+                    .collect();
+                *families.entry(positions).or_default() += 1;
+            }
+
+            families
+                .values()
+                .map(|count| {
+                    let p = *count as f64 / total;
+                    -p * p.log2()
+                })
+                .sum::<f64>()
+        };
+
+        // ties (such as a letter every candidate has in the same place) go to
+        // the letter that is in the most candidates
+        let count = |letter: char| words.iter().filter(|word| word.contains(letter)).count();
+
+        letters
+            .iter()
+            .rev()
+            .copied()
+            .map(|c| (c, entropy(c), count(c)))
+            .max_by(|(_, a, a_count), (_, b, b_count)| a.total_cmp(b).then(a_count.cmp(b_count)))
+            .map(|(c, _, _)| c)
+    }
+}
+
+// plays the game to the end with the solver, returning how it finished
+pub fn solve(game: &mut GameState, solver: &mut dyn Solver, dictionary: &[String]) -> GameStatus {
+    // every guess can only rule words out, so the solver is handed the words
+    // that were still possible last time rather than the whole dictionary.
+    // They are borrowed, scoring solves a game for every word in the
+    // dictionary and copying it each time adds up
+    let mut remaining: Vec<&str> = dictionary.iter().map(String::as_str).collect();
+
+    while !game.is_over() {
+        let pattern = game.masked_word();
+        remaining = candidates(&pattern, game.letters_wrong(), &remaining);
+
+        let guess = solver
+            .next_guess(&pattern, game.letters_wrong(), &remaining)
+            .filter(|c| game.letters().contains(c));
+
+        // a solver that has run out of ideas still has to make progress
+        let guess = match guess {
+            Some(c) => c,
+            None => match untried(&pattern, game.letters_wrong())
+                .into_iter()
+                .find(|c| game.letters().contains(c))
+            {
+                Some(c) => c,
+                None => break,
+            },
+        };
+
+        game.guess(guess);
+    }
+
+    game.status()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn candidates_fit_the_pattern() {
+        let dictionary = ["cat", "cot", "cut", "cct", "ctt", "dog", "cart"];
+
+        // 'o' was a wrong guess, and a hidden letter can't be one that has
+        // already been revealed elsewhere
+        assert_eq!(candidates("c_t", &['o'], &dictionary), ["cat", "cut"]);
+        assert_eq!(candidates("___", &[], &dictionary).len(), 6);
+    }
+
+    #[test]
+    fn frequency_ties_go_to_the_common_letter() {
+        let dictionary = ["abc"];
+
+        assert_eq!(
+            FrequencySolver.next_guess("___", &[], &dictionary),
+            Some('a')
+        );
+        assert_eq!(
+            FrequencySolver.next_guess("a__", &[], &dictionary),
+            Some('c')
+        );
+    }
+
+    #[test]
+    fn entropy_splits_the_candidates() {
+        // 'a' is in both words in the same place so it tells us nothing,
+        // 'b' and 'c' split them evenly and the more common one wins
+        let dictionary = ["ab", "ac"];
+        assert_eq!(EntropySolver.next_guess("__", &[], &dictionary), Some('c'));
+
+        // with one word left every letter is as good as the next, so it
+        // goes to the letters that are in it
+        let dictionary = ["ab"];
+        assert_eq!(EntropySolver.next_guess("__", &[], &dictionary), Some('a'));
+    }
+
+    #[test]
+    fn solving_finishes_the_game() {
+        let dictionary = words(&["cat", "dog", "bird", "fish", "horse"]);
+
+        for word in &dictionary {
+            let mut game = GameState::new(word);
+            assert_eq!(
+                solve(&mut game, &mut FrequencySolver, &dictionary),
+                GameStatus::Won
+            );
+
+            let mut game = GameState::new(word);
+            assert_eq!(
+                solve(&mut game, &mut EntropySolver, &dictionary),
+                GameStatus::Won
+            );
+        }
+
+        // a word the dictionary doesn't know is still guessed letter by letter
+        let mut game = GameState::new("zebra").with_max_wrong(26);
+        assert_eq!(
+            solve(&mut game, &mut FrequencySolver, &dictionary),
+            GameStatus::Won
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::config;
+use crate::difficulty::Difficulty;
+
+// how many finished games are kept in the history
+pub const RECENT_GAMES: usize = 10;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub wins: usize,
+    pub losses: usize,
+    pub wrong_guesses: usize,
+    #[serde(default)]
+    pub hints: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentGame {
+    pub word: String,
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub wrong: usize,
+    #[serde(default)]
+    pub hints: usize,
+}
+
+/// Everything remembered about a player between runs.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    pub games_played: usize,
+    pub current_streak: usize,
+    pub best_streak: usize,
+    pub difficulties: BTreeMap<Difficulty, Record>,
+    // newest first
+    pub recent: VecDeque<RecentGame>,
+}
+
+impl Stats {
+    // where the stats live, `None` when the platform has no config dir
+    pub fn default_path() -> Option<PathBuf> {
+        config::path("stats.json")
+    }
+
+    // a missing file is treated as a player who hasn't played yet
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match config::read(path)? {
+            Some(text) => Ok(serde_json::from_str(&text)?),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        config::write(path, &serde_json::to_string_pretty(self)?)
+    }
+
+    // `wrong` includes the hints, which are also counted on their own
+    pub fn record(
+        &mut self,
+        word: &str,
+        difficulty: Difficulty,
+        won: bool,
+        wrong: usize,
+        hints: usize,
+    ) {
+        self.games_played += 1;
+
+        let record = self.difficulties.entry(difficulty).or_default();
+        record.wrong_guesses += wrong;
+        record.hints += hints;
+        if won {
+            record.wins += 1;
+            self.current_streak += 1;
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            record.losses += 1;
+            self.current_streak = 0;
+        }
+
+        self.recent.push_front(RecentGame {
+            word: word.to_string(),
+            difficulty,
+            won,
+            wrong,
+            hints,
+        });
+        self.recent.truncate(RECENT_GAMES);
+    }
+
+    pub fn wins(&self) -> usize {
+        self.difficulties.values().map(|r| r.wins).sum()
+    }
+
+    pub fn losses(&self) -> usize {
+        self.difficulties.values().map(|r| r.losses).sum()
+    }
+
+    pub fn average_wrong(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+
+        let wrong: usize = self.difficulties.values().map(|r| r.wrong_guesses).sum();
+        wrong as f64 / self.games_played as f64
+    }
+}
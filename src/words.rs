@@ -0,0 +1,245 @@
+use quad_rand::{ChooseRandom, RandGenerator};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::alphabet::Alphabet;
+use crate::difficulty::Difficulty;
+use crate::engine::{GameMode, GameState};
+use crate::rng::Rng;
+
+// the default word lists, compiled into the binary
+pub const EASY_WORDS: &str = include_str!("../assets/easy-words.txt");
+pub const MEDIUM_WORDS: &str = include_str!("../assets/medium-words.txt");
+pub const HARD_WORDS: &str = include_str!("../assets/hard-words.txt");
+pub const FULL_WORD_LIST: &str = include_str!("../assets/full-word-list.txt");
+pub const PHRASES: &str = include_str!("../assets/phrases.txt");
+
+// characters other than letters that a secret word or phrase may use
+pub const PUNCTUATION: [char; 3] = [' ', '\'', '-'];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Word {
+    pub text: String,
+    // a definition or clue, for sources that have them
+    pub clue: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum WordSourceError {
+    Io(PathBuf, io::Error),
+    Empty(Difficulty),
+}
+
+impl fmt::Display for WordSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            Self::Empty(difficulty) => write!(f, "no {} words available", difficulty.as_str()),
+        }
+    }
+}
+
+impl Error for WordSourceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err),
+            Self::Empty(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SecretWordError {
+    Empty,
+    NotLetters,
+    NotInDictionary,
+}
+
+impl fmt::Display for SecretWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the word can't be empty"),
+            Self::NotLetters => write!(f, "the word can only use the letters a-z, spaces, ' and -"),
+            Self::NotInDictionary => write!(f, "that word isn't in the dictionary"),
+        }
+    }
+}
+
+impl Error for SecretWordError {}
+
+// checks a word or phrase typed in by a player, optionally against a list of
+// known words which every word of a phrase has to be in
+pub fn check_secret_word(word: &str, dictionary: Option<&[String]>) -> Result<(), SecretWordError> {
+    let alphabet = Alphabet::english();
+
+    if !word.chars().any(|c| alphabet.is_letter(c)) {
+        Err(SecretWordError::Empty)
+    } else if !word
+        .chars()
+        .all(|c| alphabet.is_letter(c) || PUNCTUATION.contains(&c))
+    {
+        Err(SecretWordError::NotLetters)
+    } else if dictionary.is_some_and(|words| {
+        word.split_whitespace()
+            .any(|part| !words.iter().any(|w| w == part))
+    }) {
+        Err(SecretWordError::NotInDictionary)
+    } else {
+        Ok(())
+    }
+}
+
+/// Somewhere secret words come from.
+pub trait WordSource: fmt::Debug {
+    // every word this source has for the difficulty
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError>;
+
+    fn clue(&self, _word: &str) -> Option<&str> {
+        None
+    }
+
+    fn pick(
+        &mut self,
+        difficulty: Difficulty,
+        rng: &RandGenerator,
+    ) -> Result<Word, WordSourceError> {
+        let words = self.words(difficulty)?;
+        let text = match words.choose_with_state(rng) {
+            Some(text) => text.clone(),
+            None => return Err(WordSourceError::Empty(difficulty)),
+        };
+        let clue = self.clue(&text).map(str::to_string);
+
+        Ok(Word { text, clue })
+    }
+}
+
+// a new game with a word picked from `words` with `seed`, which the game
+// keeps so it can be played again with --seed. The clue only fits the word
+// in a normal game, an evil one moves on to other words
+pub fn new_game(
+    words: &mut dyn WordSource,
+    difficulty: Difficulty,
+    mode: GameMode,
+    alphabet: Alphabet,
+    seed: u64,
+) -> Result<GameState, WordSourceError> {
+    let word = words.pick(difficulty, &Rng::new(seed))?;
+    let game = match mode {
+        GameMode::Normal => GameState::new_in(&word.text, alphabet).with_clue(word.clue),
+        GameMode::Evil => GameState::evil_in(&word.text, words.words(difficulty)?, alphabet),
+    };
+
+    Ok(game.with_seed(seed))
+}
+
+// how long a word or phrase is for picking its difficulty, only letters
+// count and not spaces or punctuation
+pub fn letter_count(word: &str) -> usize {
+    word.chars().filter(|c| c.is_alphabetic()).count()
+}
+
+// one word or phrase per line, blank lines are skipped
+pub fn parse_list(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Word lists that live in the binary itself.
+#[derive(Debug)]
+pub struct EmbeddedWords {
+    lists: HashMap<Difficulty, Vec<String>>,
+}
+
+impl EmbeddedWords {
+    pub fn new(easy: &'static str, medium: &'static str, hard: &'static str) -> Self {
+        let lists = [
+            (Difficulty::Easy, parse_list(easy)),
+            (Difficulty::Medium, parse_list(medium)),
+            (Difficulty::Hard, parse_list(hard)),
+        ];
+
+        Self {
+            lists: lists.into_iter().collect(),
+        }
+    }
+}
+
+impl Default for EmbeddedWords {
+    fn default() -> Self {
+        Self::new(EASY_WORDS, MEDIUM_WORDS, HARD_WORDS)
+    }
+}
+
+impl WordSource for EmbeddedWords {
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError> {
+        Ok(self.lists.get(&difficulty).map_or(&[], Vec::as_slice))
+    }
+}
+
+/// A directory holding `easy-words.txt`, `medium-words.txt` and `hard-words.txt`.
+/// Each file is read the first time its difficulty is asked for.
+#[derive(Debug)]
+pub struct DirectoryWords {
+    path: PathBuf,
+    lists: HashMap<Difficulty, Vec<String>>,
+}
+
+impl DirectoryWords {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            lists: HashMap::new(),
+        }
+    }
+}
+
+impl WordSource for DirectoryWords {
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError> {
+        if !self.lists.contains_key(&difficulty) {
+            let path = self.path.join(format!("{}-words.txt", difficulty.as_str()));
+            let text = fs::read_to_string(&path).map_err(|err| WordSourceError::Io(path, err))?;
+            self.lists.insert(difficulty, parse_list(&text));
+        }
+
+        Ok(&self.lists[&difficulty])
+    }
+}
+
+/// A plain list of words, sorted into difficulties by their length.
+#[derive(Debug)]
+pub struct MemoryWords {
+    lists: HashMap<Difficulty, Vec<String>>,
+}
+
+impl MemoryWords {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut lists: HashMap<Difficulty, Vec<String>> = HashMap::new();
+        for word in words {
+            let word = word.into().to_lowercase();
+            lists
+                .entry(Difficulty::from_length(letter_count(&word)))
+                .or_default()
+                .push(word);
+        }
+
+        Self { lists }
+    }
+}
+
+impl WordSource for MemoryWords {
+    fn words(&mut self, difficulty: Difficulty) -> Result<&[String], WordSourceError> {
+        Ok(self.lists.get(&difficulty).map_or(&[], Vec::as_slice))
+    }
+}